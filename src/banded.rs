@@ -0,0 +1,427 @@
+use crate::{error::Error, iterative::LinearOperator, matrix::Matrix, vector::Vector, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+use std::ops::{Index, IndexMut};
+
+/// Square tridiagonal matrix of size `n`, stored as its subdiagonal, diagonal and superdiagonal.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Tridiagonal<K> {
+    lower: Vec<K>,
+    diag: Vec<K>,
+    upper: Vec<K>,
+}
+
+/// Square banded matrix of size `n` with `kl` subdiagonals and `ku` superdiagonals. Row `i` stores
+/// the elements of columns `i - kl` up to `i + ku`, so the storage takes `n * (kl + ku + 1)`
+/// elements.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Banded<K> {
+    data: Vec<K>,
+    n: usize,
+    kl: usize,
+    ku: usize,
+}
+
+/// LU decomposition of a [`Banded`] matrix computed with partial pivoting. Row exchanges can
+/// widen the upper bandwidth of `U` to `kl + ku`, while `L` keeps its `kl` subdiagonals; both are
+/// kept in band storage, so factorizing and solving take `O(n * kl * (kl + ku))` operations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedLu<K> {
+    u: Banded<K>,
+    l: Vec<K>,
+    pivots: Vec<usize>,
+    sign: K,
+    singular: bool,
+}
+
+impl<K: Scalar> Tridiagonal<K> {
+    /// Creates a [`Tridiagonal`] matrix from its subdiagonal, diagonal and superdiagonal. Returns
+    /// an error if the off-diagonals are not one element shorter than the diagonal.
+    pub fn new(lower: Vec<K>, diag: Vec<K>, upper: Vec<K>) -> Result<Tridiagonal<K>> {
+        let expected = diag.len().saturating_sub(1);
+        for found in [lower.len(), upper.len()] {
+            if found != expected {
+                return Err(Error::DimensionMismatch { expected, found }.into());
+            }
+        }
+        Ok(Tridiagonal { lower, diag, upper })
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    /// Returns the subdiagonal, of length `n - 1`.
+    pub fn lower(&self) -> &[K] {
+        &self.lower
+    }
+
+    /// Returns the diagonal, of length `n`.
+    pub fn diag(&self) -> &[K] {
+        &self.diag
+    }
+
+    /// Returns the superdiagonal, of length `n - 1`.
+    pub fn upper(&self) -> &[K] {
+        &self.upper
+    }
+
+    /// Multiplies this [`Tridiagonal`] matrix by the given [`Vector`].
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let n = self.size();
+        assert_eq!(n, vec.len(), "dimensions of the operands must agree");
+        (0..n)
+            .map(|i| {
+                let mut acc = self.diag[i] * vec[i];
+                if i > 0 {
+                    acc += self.lower[i - 1] * vec[i - 1];
+                }
+                if i + 1 < n {
+                    acc += self.upper[i] * vec[i + 1];
+                }
+                acc
+            })
+            .collect()
+    }
+
+    /// Solves `Ax = b` for `x` in `O(n)` with the Thomas algorithm, which is Gaussian elimination
+    /// without pivoting. It is stable for diagonally dominant or symmetric positive-definite
+    /// matrices; convert other matrices to [`Banded`] and use [`Banded::lu`] instead. Returns an
+    /// error if a pivot is NaN or below `n * ε` times the largest entry of its row.
+    /// ```
+    /// # use matrix::{banded::Tridiagonal, vector::Vector};
+    /// let a = Tridiagonal::new(vec![1., 1.], vec![4., 4., 4.], vec![1., 1.]).unwrap();
+    /// assert_eq!(a.solve(&Vector::from([5., 6., 5.])).unwrap(), Vector::from([1., 1., 1.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.size();
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        let size = <K::Real as NumCast>::from(n).unwrap();
+        let mut upper = vec![K::zero(); n];
+        let mut x = b.clone();
+        for i in 0..n {
+            let mut pivot = self.diag[i];
+            let mut scale = self.diag[i].modulus();
+            if i > 0 {
+                pivot -= self.lower[i - 1] * upper[i - 1];
+                let tmp = self.lower[i - 1] * x[i - 1];
+                x[i] -= tmp;
+                scale = scale.max(self.lower[i - 1].modulus());
+            }
+            if i + 1 < n {
+                scale = scale.max(self.upper[i].modulus());
+            }
+            let modulus = pivot.modulus();
+            if modulus <= size * K::Real::epsilon() * scale || modulus.is_nan() {
+                return Err(Error::Singular.into());
+            }
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] /= pivot;
+        }
+        for i in (0..n.saturating_sub(1)).rev() {
+            let tmp = upper[i] * x[i + 1];
+            x[i] -= tmp;
+        }
+        Ok(x)
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Banded::from(self).to_matrix()
+    }
+}
+
+impl<K: Scalar> Banded<K> {
+    /// Creates a [`Banded`] matrix of size `n` with `kl` subdiagonals and `ku` superdiagonals,
+    /// filled with zeros.
+    pub fn zeros(n: usize, kl: usize, ku: usize) -> Banded<K> {
+        Banded {
+            data: vec![K::zero(); n * (kl + ku + 1)],
+            n,
+            kl,
+            ku,
+        }
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of subdiagonals `kl`.
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    /// Returns the number of superdiagonals `ku`.
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    /// Returns the element at row `i` and column `j`, which is zero outside of the band.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.n && j < self.n, "index out of bounds");
+        if self.in_band(i, j) {
+            self[(i, j)]
+        } else {
+            K::zero()
+        }
+    }
+
+    /// Multiplies this [`Banded`] matrix by the given [`Vector`].
+    /// ```
+    /// # use matrix::{banded::Banded, vector::Vector};
+    /// let mut a = Banded::zeros(3, 0, 1);
+    /// (0..3).for_each(|i| a[(i, i)] = 2.);
+    /// (0..2).for_each(|i| a[(i, i + 1)] = 1.);
+    /// assert_eq!(a.mul_vec(&Vector::from([1., 2., 3.])), Vector::from([4., 7., 6.]));
+    /// ```
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        (0..self.n)
+            .map(|i| {
+                self.columns_of(i)
+                    .fold(K::zero(), |acc, j| acc + self[(i, j)] * vec[j])
+            })
+            .collect()
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let mut res = Matrix::from_vec(self.n, self.n, vec![K::zero(); self.n * self.n]).unwrap();
+        for i in 0..self.n {
+            self.columns_of(i).for_each(|j| res[(i, j)] = self[(i, j)]);
+        }
+        res
+    }
+
+    /// Computes the LU decomposition with partial pivoting, see [`BandedLu`].
+    pub fn lu(&self) -> BandedLu<K> {
+        BandedLu::new(self)
+    }
+
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        j + self.kl >= i && j <= i + self.ku
+    }
+
+    /// Returns the columns of row `i` that lie within the band.
+    fn columns_of(&self, i: usize) -> std::ops::Range<usize> {
+        i.saturating_sub(self.kl)..(i + self.ku + 1).min(self.n)
+    }
+}
+
+impl<K: Scalar> BandedLu<K> {
+    /// Factorizes the given [`Banded`] matrix. A singular matrix can be factorized, but
+    /// [`BandedLu::solve`] will return an error for it. A pivot counts as zero when it is below
+    /// `n * ε` times the largest entry of the matrix, and a column holding a NaN makes the matrix
+    /// singular as well.
+    pub fn new(a: &Banded<K>) -> BandedLu<K> {
+        let (n, kl) = (a.n, a.kl);
+        let mut u = Banded::zeros(n, kl, kl + a.ku);
+        for i in 0..n {
+            a.columns_of(i).for_each(|j| u[(i, j)] = a[(i, j)]);
+        }
+        let tolerance = a
+            .data
+            .iter()
+            .fold(K::Real::zero(), |max, x| max.max(x.modulus()))
+            * K::Real::epsilon()
+            * <K::Real as NumCast>::from(n).unwrap();
+        let mut l = vec![K::zero(); n * kl];
+        let mut pivots = Vec::with_capacity(n);
+        let mut sign = K::one();
+        let mut singular = false;
+
+        for k in 0..n {
+            let last_row = (k + kl).min(n - 1);
+            let last_column = (k + u.ku).min(n - 1);
+            if (k..=last_row).any(|i| u[(i, k)].modulus().is_nan()) {
+                singular = true;
+                pivots.push(k);
+                continue;
+            }
+            let p = (k..=last_row)
+                .max_by(|&x, &y| {
+                    u[(x, k)]
+                        .modulus()
+                        .partial_cmp(&u[(y, k)].modulus())
+                        .unwrap()
+                })
+                .unwrap();
+            pivots.push(p);
+            if p != k {
+                sign = -sign;
+                for j in k..=last_column {
+                    let tmp = u[(p, j)];
+                    u[(p, j)] = u[(k, j)];
+                    u[(k, j)] = tmp;
+                }
+            }
+            if u[(k, k)].modulus() <= tolerance {
+                singular = true;
+            }
+            if u[(k, k)] == K::zero() {
+                continue;
+            }
+            for i in (k + 1)..=last_row {
+                let ratio = u[(i, k)] / u[(k, k)];
+                l[k * kl + i - k - 1] = ratio;
+                u[(i, k)] = K::zero();
+                for j in (k + 1)..=last_column {
+                    let tmp = u[(k, j)] * ratio;
+                    u[(i, j)] -= tmp;
+                }
+            }
+        }
+        BandedLu {
+            u,
+            l,
+            pivots,
+            sign,
+            singular,
+        }
+    }
+
+    /// Returns the upper triangular factor `U`, whose upper bandwidth is `kl + ku`. Its
+    /// subdiagonals are zero.
+    pub fn u(&self) -> &Banded<K> {
+        &self.u
+    }
+
+    /// Returns whether a pivot vanished, in which case [`BandedLu::solve`] returns an error.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Calculates the determinant as the signed product of the pivots.
+    pub fn determinant(&self) -> K {
+        (0..self.u.n).fold(self.sign, |acc, i| acc * self.u[(i, i)])
+    }
+
+    /// Solves `Ax = b` for `x`, replaying the row exchanges and eliminations on `b` before back
+    /// substitution with `U`.
+    /// ```
+    /// # use matrix::{banded::Banded, vector::Vector};
+    /// let mut a = Banded::zeros(2, 1, 1);
+    /// a[(0, 1)] = 1.;
+    /// a[(1, 0)] = 2.;
+    /// let lu = a.lu();
+    /// assert_eq!(lu.solve(&Vector::from([3., 4.])).unwrap(), Vector::from([2., 3.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let (n, kl) = (self.u.n, self.u.kl);
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        if self.singular {
+            return Err(Error::Singular.into());
+        }
+        let mut x = b.clone();
+        for (k, &p) in self.pivots.iter().enumerate() {
+            x.swap(k, p);
+            for i in (k + 1)..=(k + kl).min(n - 1) {
+                let tmp = self.l[k * kl + i - k - 1] * x[k];
+                x[i] -= tmp;
+            }
+        }
+        for i in (0..n).rev() {
+            let s = ((i + 1)..(i + self.u.ku + 1).min(n))
+                .fold(x[i], |acc, j| acc - self.u[(i, j)] * x[j]);
+            x[i] = s / self.u[(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+/// Panics if `(i, j)` lies outside of the band.
+impl<K> Index<(usize, usize)> for Banded<K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        assert!(i < self.n && j < self.n, "index out of bounds");
+        assert!(
+            j + self.kl >= i && j <= i + self.ku,
+            "index outside of the band"
+        );
+        &self.data[i * (self.kl + self.ku + 1) + j + self.kl - i]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for Banded<K> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        assert!(i < self.n && j < self.n, "index out of bounds");
+        assert!(
+            j + self.kl >= i && j <= i + self.ku,
+            "index outside of the band"
+        );
+        &mut self.data[i * (self.kl + self.ku + 1) + j + self.kl - i]
+    }
+}
+
+impl<K: Scalar> From<&Tridiagonal<K>> for Banded<K> {
+    fn from(value: &Tridiagonal<K>) -> Self {
+        let n = value.size();
+        let mut res = Banded::zeros(n, 1, 1);
+        for i in 0..n {
+            res[(i, i)] = value.diag[i];
+            if i + 1 < n {
+                res[(i + 1, i)] = value.lower[i];
+                res[(i, i + 1)] = value.upper[i];
+            }
+        }
+        res
+    }
+}
+
+impl<K: Scalar> From<&Tridiagonal<K>> for Matrix<K> {
+    fn from(value: &Tridiagonal<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K: Scalar> From<&Banded<K>> for Matrix<K> {
+    fn from(value: &Banded<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K: Scalar> LinearOperator<K> for Tridiagonal<K> {
+    fn rows(&self) -> usize {
+        self.size()
+    }
+
+    fn columns(&self) -> usize {
+        self.size()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Scalar> LinearOperator<K> for Banded<K> {
+    fn rows(&self) -> usize {
+        self.n
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
@@ -65,4 +65,13 @@ mod add_subtract_scale {
         u *= 2.;
         assert_eq!(u, Matrix::from([[2., 4.], [6., 8.]]));
     }
+
+    #[test]
+    fn test_matrix_scl_f64() {
+        let u = Matrix::from([[1., 2.], [3., 1e-300]]);
+        let mut v = u * 0.1;
+        assert_eq!(v, Matrix::from([[0.1, 0.2], [0.30000000000000004, 1e-301]]));
+        v *= 10.;
+        assert_eq!(v[0][0], 1.);
+    }
 }
@@ -0,0 +1,160 @@
+use matrix::{
+    banded::{Banded, Tridiagonal},
+    vector::Vector,
+};
+
+/// Implicit Euler step of the 1D heat equation on `n` interior points with fixed ends.
+fn diffusion(n: usize, r: f32) -> Tridiagonal<f32> {
+    Tridiagonal::new(vec![-r; n - 1], vec![1. + 2. * r; n], vec![-r; n - 1]).unwrap()
+}
+
+fn main() {
+    let a = diffusion(5, 0.5);
+    let u = Vector::from([0., 0., 1., 0., 0.]);
+    if let Ok(next) = a.solve(&u) {
+        println!("{}", next);
+    }
+    let lu = Banded::from(&a).lu();
+    println!("{}", lu.determinant());
+}
+
+#[cfg(test)]
+mod banded {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{error::Error, matrix::Matrix};
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &Vector<f32>) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v.iter())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    /// Copies the band of a dense [`Matrix`].
+    fn band(a: &Matrix<f32>, kl: usize, ku: usize) -> Banded<f32> {
+        let n = a.rows();
+        let mut res = Banded::zeros(n, kl, ku);
+        for i in 0..n {
+            for j in i.saturating_sub(kl)..(i + ku + 1).min(n) {
+                res[(i, j)] = a[i][j];
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn tridiagonal() {
+        let a = diffusion(4, 1.);
+        assert_eq!(
+            a.to_matrix(),
+            Matrix::from([
+                [3., -1., 0., 0.],
+                [-1., 3., -1., 0.],
+                [0., -1., 3., -1.],
+                [0., 0., -1., 3.]
+            ])
+        );
+        assert_eq!(a.size(), 4);
+        let x = Vector::from([1., 2., 3., 4.]);
+        assert_eq!(a.mul_vec(&x), Vector::from([1., 2., 3., 9.]));
+        assert_vector_approx_eq(&a.solve(&a.mul_vec(&x)).unwrap(), &x);
+
+        let b = Vector::from([1., -2., 0.5, 3.]);
+        assert_vector_approx_eq(&a.solve(&b).unwrap(), &a.to_matrix().solve(&b).unwrap());
+    }
+
+    #[test]
+    fn tridiagonal_errors() {
+        assert!(Tridiagonal::new(vec![1.], vec![1., 2., 3.], vec![1., 1.]).is_err());
+        let a = Tridiagonal::new(vec![1.], vec![1., 1.], vec![1.]).unwrap();
+        let err = a.solve(&Vector::from([1., 2.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        assert!(diffusion(3, 1.).solve(&Vector::from([1., 2.])).is_err());
+        let a = Tridiagonal::new(vec![1.], vec![f64::NAN, 1.], vec![1.]).unwrap();
+        let err = a.solve(&Vector::from([1., 2.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+
+        // Singular for Thomas but not for the pivoting banded LU.
+        let a = Tridiagonal::new(vec![1., 1.], vec![0., 1., 2.], vec![2., 1.]).unwrap();
+        assert!(a.solve(&Vector::from([2., 3., 3.])).is_err());
+        let x = Banded::from(&a)
+            .lu()
+            .solve(&Vector::from([2., 3., 3.]))
+            .unwrap();
+        assert_vector_approx_eq(&x, &Vector::from([1., 1., 1.]));
+    }
+
+    #[test]
+    fn banded() {
+        let dense = Matrix::from([
+            [4., 1., 2., 0., 0.],
+            [1., 5., 1., 3., 0.],
+            [0., 2., 6., 1., 1.],
+            [0., 0., 1., 7., 2.],
+            [0., 0., 0., 3., 8.],
+        ]);
+        let a = band(&dense, 1, 2);
+        assert_eq!((a.lower_bandwidth(), a.upper_bandwidth()), (1, 2));
+        assert_eq!(a.to_matrix(), dense);
+        assert_eq!(Matrix::from(&a), dense);
+        assert_eq!((a.get(4, 0), a.get(1, 3)), (0., 3.));
+
+        let d = dense.clone();
+        let x = Vector::from([1., -1., 2., 0.5, -2.]);
+        assert_eq!(a.mul_vec(&x), d.mul_vec(&x));
+        let lu = a.lu();
+        assert!(!lu.is_singular());
+        assert_vector_approx_eq(&lu.solve(&d.mul_vec(&x)).unwrap(), &x);
+        assert_approx_eq!(
+            f32,
+            lu.determinant(),
+            d.determinant().unwrap(),
+            epsilon = 1e-1
+        );
+    }
+
+    #[test]
+    fn banded_pivoting() {
+        let dense = Matrix::from([
+            [0., 1., 0., 0.],
+            [2., 0., 3., 0.],
+            [0., 4., 1., 5.],
+            [0., 0., 1., 1.],
+        ]);
+        let a = band(&dense, 1, 1);
+        let lu = a.lu();
+        assert_eq!(lu.u().upper_bandwidth(), 2);
+        let b = Vector::from([1., 2., 3., 4.]);
+        assert_vector_approx_eq(&lu.solve(&b).unwrap(), &dense.solve(&b).unwrap());
+        assert_approx_eq!(f32, lu.determinant(), 8., epsilon = 1e-5);
+    }
+
+    #[test]
+    fn banded_singular() {
+        let a = band(
+            &Matrix::from([[1., 2., 0.], [2., 4., 0.], [0., 1., 1.]]),
+            1,
+            1,
+        );
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.);
+        let err = lu.solve(&Vector::from([1., 2., 3.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+
+        let mut a = band(&Matrix::identity(4), 1, 1);
+        a[(2, 1)] = f32::NAN;
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        let err = lu.solve(&Vector::from([1., 2., 3., 4.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+    }
+
+    #[test]
+    #[should_panic]
+    fn banded_outside_of_band() {
+        let mut a = Banded::zeros(3, 0, 1);
+        a[(1, 0)] = 1.;
+    }
+}
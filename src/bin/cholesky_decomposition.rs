@@ -0,0 +1,51 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+    if let Ok(cholesky) = u.cholesky() {
+        println!("{}", cholesky.l());
+        if let Ok(x) = cholesky.solve(&Vector::from([0., 6., 39.])) {
+            println!("{}", x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod cholesky_decomposition {
+    use super::*;
+    use float_cmp::*;
+
+    #[test]
+    fn matrix_cholesky() {
+        let u = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let cholesky = u.cholesky().unwrap();
+        assert_eq!(
+            cholesky.l(),
+            &Matrix::from([[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]])
+        );
+        assert_eq!(cholesky.l().mul_mat(&cholesky.l().transpose()), u);
+    }
+
+    #[test]
+    fn matrix_cholesky_solve() {
+        let u = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        let cholesky = u.cholesky().unwrap();
+        cholesky
+            .solve(&Vector::from([0., 6., 39.]))
+            .unwrap()
+            .iter()
+            .zip([1., 1., 1.])
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-5));
+        assert!(cholesky.solve(&Vector::from([1., 2.])).is_err());
+    }
+
+    #[test]
+    fn matrix_cholesky_not_spd() {
+        assert!(Matrix::from([[1., 2.], [2., 1.]]).cholesky().is_err());
+        assert!(Matrix::from([[1., 0.], [0., 0.]]).cholesky().is_err());
+        assert!(Matrix::from([[2., 1.], [0., 2.]]).cholesky().is_err());
+        assert!(Matrix::from([[1., 2., 3.], [4., 5., 6.]])
+            .cholesky()
+            .is_err());
+    }
+}
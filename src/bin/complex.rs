@@ -0,0 +1,218 @@
+use matrix::{matrix::Matrix, vector::Vector};
+use num::Complex;
+
+fn c(re: f32, im: f32) -> Complex<f32> {
+    Complex::new(re, im)
+}
+
+fn main() {
+    let u = Vector::from([c(3., 4.), c(0., 1.)]);
+    println!("{} {}", u.dot(&u), u.norm());
+
+    let a = Matrix::from([[c(4., 0.), c(1., -2.)], [c(1., 2.), c(6., 0.)]]);
+    println!("{}", a.is_hermitian());
+    if let Ok(x) = a.solve(&Vector::from([c(1., 0.), c(0., 1.)])) {
+        println!("{}", x);
+    }
+    if let Ok(eigenvalues) = a.eigenvalues() {
+        println!("{:?}", eigenvalues);
+    }
+}
+
+#[cfg(test)]
+mod complex {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{
+        iterative::{conjugate_gradient, gmres, Identity},
+        ordering::Ordering,
+        sparse::Coo,
+        sparse_cholesky::SparseCholesky,
+        sparse_lu::SparseLu,
+    };
+
+    fn assert_complex_approx_eq(a: Complex<f32>, b: Complex<f32>) {
+        assert_approx_eq!(f32, a.re, b.re, epsilon = 1e-4);
+        assert_approx_eq!(f32, a.im, b.im, epsilon = 1e-4);
+    }
+
+    fn assert_vector_approx_eq(u: &Vector<Complex<f32>>, v: &Vector<Complex<f32>>) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v.iter())
+            .for_each(|(a, b)| assert_complex_approx_eq(*a, *b));
+    }
+
+    fn assert_matrix_approx_eq(u: &Matrix<Complex<f32>>, v: &Matrix<Complex<f32>>) {
+        assert_eq!(u.shape(), v.shape());
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_complex_approx_eq(*a, *b));
+    }
+
+    fn general() -> Matrix<Complex<f32>> {
+        Matrix::from([
+            [c(2., 1.), c(1., 0.), c(0., -1.)],
+            [c(1., -1.), c(3., 0.), c(1., 2.)],
+            [c(0., 2.), c(-1., 1.), c(4., -1.)],
+        ])
+    }
+
+    fn hermitian() -> Matrix<Complex<f32>> {
+        Matrix::from([
+            [c(4., 0.), c(1., -2.), c(0., 1.)],
+            [c(1., 2.), c(6., 0.), c(1., 0.)],
+            [c(0., -1.), c(1., 0.), c(5., 0.)],
+        ])
+    }
+
+    #[test]
+    fn vector_hermitian_dot() {
+        let u = Vector::from([c(3., 4.), c(0., 1.)]);
+        let v = Vector::from([c(1., 0.), c(2., 0.)]);
+        assert_eq!(u.dot(&u), c(26., 0.));
+        assert_eq!(u.dot(&v), c(3., -6.));
+        assert_eq!(v.dot(&u), c(3., 6.));
+        assert_approx_eq!(f32, u.norm(), 26f32.sqrt(), epsilon = 1e-6);
+        assert_eq!((u.norm_1(), u.norm_inf()), (6., 5.));
+    }
+
+    #[test]
+    fn matrix_conjugate_transpose() {
+        let a = general();
+        let ah = a.conjugate_transpose();
+        assert_eq!(ah[0][1], c(1., 1.));
+        assert_eq!(ah[2][0], c(0., 1.));
+        assert!(!a.is_hermitian());
+        assert!(hermitian().is_hermitian());
+        assert!(!hermitian().is_symmetric());
+        assert!(Matrix::from([[c(1., 1.)]]).is_symmetric());
+        assert!(!Matrix::from([[c(1., 1.)]]).is_hermitian());
+    }
+
+    #[test]
+    fn complex_lu() {
+        let a = general();
+        let x = Vector::from([c(1., 0.), c(0., 1.), c(-1., 2.)]);
+        let b = a.mul_vec(&x);
+        assert_vector_approx_eq(&a.solve(&b).unwrap(), &x);
+        let lu = a.lu().unwrap();
+        let inverse = lu.inverse().unwrap();
+        assert_matrix_approx_eq(&inverse.mul_mat(&a), &Matrix::identity(3));
+    }
+
+    #[test]
+    fn hermitian_cholesky_ldlt() {
+        let a = hermitian();
+        let cholesky = a.cholesky().unwrap();
+        let l = cholesky.l().clone();
+        assert_matrix_approx_eq(&l.mul_mat(&l.conjugate_transpose()), &a);
+        (0..3).for_each(|i| assert_eq!(l[i][i].im, 0.));
+
+        let x = Vector::from([c(1., -1.), c(2., 0.), c(0., 3.)]);
+        let b = a.mul_vec(&x);
+        assert_vector_approx_eq(&cholesky.solve(&b).unwrap(), &x);
+        assert_vector_approx_eq(&a.ldlt().unwrap().solve(&b).unwrap(), &x);
+        assert!(general().cholesky().is_err());
+    }
+
+    #[test]
+    fn complex_qr() {
+        let a = Matrix::from([
+            [c(1., 1.), c(2., 0.)],
+            [c(0., -1.), c(1., 1.)],
+            [c(3., 0.), c(0., 2.)],
+        ]);
+        for qr in [a.qr(), a.qr_givens()] {
+            let q = qr.q().clone();
+            assert_matrix_approx_eq(&q.mul_mat(qr.r()), &a);
+            assert_matrix_approx_eq(&q.conjugate_transpose().mul_mat(&q), &Matrix::identity(3));
+        }
+    }
+
+    #[test]
+    fn complex_svd() {
+        let a = general();
+        let svd = a.svd();
+        let mut scaled = svd.u().clone();
+        (0..3).for_each(|i| {
+            (0..3).for_each(|j| scaled[i][j] *= Complex::from(svd.singular_values()[j]))
+        });
+        assert_matrix_approx_eq(&scaled.mul_mat(svd.vt()), &a);
+        assert_eq!(svd.rank(svd.default_tolerance()), 3);
+    }
+
+    #[test]
+    fn hermitian_eigen() {
+        let a = Matrix::from([[c(2., 0.), c(0., -1.)], [c(0., 1.), c(2., 0.)]]);
+        let eigen = a.symmetric_eigen().unwrap();
+        assert_approx_eq!(f32, eigen.eigenvalues()[0], 1., epsilon = 1e-5);
+        assert_approx_eq!(f32, eigen.eigenvalues()[1], 3., epsilon = 1e-5);
+
+        let a = hermitian();
+        let eigen = a.symmetric_eigen().unwrap();
+        let mut scaled = eigen.eigenvectors().clone();
+        (0..3).for_each(|i| {
+            (0..3).for_each(|j| scaled[i][j] *= Complex::from(eigen.eigenvalues()[j]))
+        });
+        assert_matrix_approx_eq(&a.mul_mat(eigen.eigenvectors()), &scaled);
+        assert!(general().symmetric_eigen().is_err());
+    }
+
+    #[test]
+    fn complex_schur() {
+        let a = general();
+        let schur = a.schur().unwrap();
+        let (t, z) = (schur.t(), schur.z().unwrap());
+        (0..3_usize).for_each(|i| (0..i).for_each(|j| assert_eq!(t[i][j], c(0., 0.))));
+        assert_matrix_approx_eq(&z.mul_mat(t).mul_mat(&z.conjugate_transpose()), &a);
+
+        let eigenvalues = Matrix::from([[c(1., 0.), c(0., 1.)], [c(0., 1.), c(1., 0.)]])
+            .eigenvalues()
+            .unwrap();
+        assert_eq!(eigenvalues.len(), 2);
+        assert_complex_approx_eq(eigenvalues[0] + eigenvalues[1], c(2., 0.));
+        assert_complex_approx_eq(eigenvalues[0] * eigenvalues[1], c(2., 0.));
+    }
+
+    #[test]
+    fn complex_krylov() {
+        let a = hermitian();
+        let x = Vector::from([c(1., 0.), c(0., 1.), c(-1., 1.)]);
+        let b = a.mul_vec(&x);
+        let res = conjugate_gradient(&a, &b, &Identity, &Default::default()).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &x);
+
+        let a = general();
+        let b = a.mul_vec(&x);
+        let res = gmres(&a, &b, &Identity, &Default::default()).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &x);
+    }
+
+    #[test]
+    fn complex_sparse() {
+        let n = 6;
+        let mut coo = Coo::new(n, n);
+        for k in 0..n {
+            coo.push(k, k, c(6., 0.));
+            if k + 1 < n {
+                coo.push(k, k + 1, c(-1., 1.));
+                coo.push(k + 1, k, c(-1., -1.));
+            }
+        }
+        coo.push(0, n - 1, c(0., 2.));
+        coo.push(n - 1, 0, c(0., -2.));
+        let a = coo.to_csc();
+        let x: Vector<Complex<f32>> = (0..n).map(|i| c(i as f32, 1. - i as f32)).collect();
+        let b = a.mul_vec(&x);
+        for ordering in [Ordering::Natural, Ordering::ReverseCuthillMcKee] {
+            let cholesky = SparseCholesky::new(&a, ordering).unwrap();
+            assert_vector_approx_eq(&cholesky.solve(&b).unwrap(), &x);
+            let lu = SparseLu::new(&a, ordering).unwrap();
+            assert_vector_approx_eq(&lu.solve(&b).unwrap(), &x);
+        }
+    }
+}
@@ -1,13 +1,8 @@
-use matrix::{vector::Vector, Scalar};
-use num::pow::Pow;
-use std::iter::Sum;
+use matrix::{vector::Vector, RealScalar};
 
 /// Calculates the Cosine of two [`Vector`]'s.
-fn angle_cos<K: Scalar<K>>(u: &Vector<K>, v: &Vector<K>) -> f32
-where
-    f32: Sum<K> + From<K> + Sum<<K as Pow<f32>>::Output>,
-{
-    f32::from(u.dot(v.clone())) / (u.clone().norm() * v.clone().norm())
+fn angle_cos<K: RealScalar>(u: &Vector<K>, v: &Vector<K>) -> K {
+    u.dot(v) / (u.norm() * v.norm())
 }
 
 fn main() {
@@ -1,37 +1,77 @@
 use matrix::matrix::Matrix;
 
 fn main() {
-    let mut u = Matrix::from([
+    let u = Matrix::from([
         [8., 5., -2., 4.],
         [4., 2.5, 20., 4.],
         [8., 5., 1., 4.],
         [28., -4., 17., 1.],
     ]);
     println!("{}", u);
-    println!("{}", u.determinant());
+    if let Ok(determinant) = u.determinant() {
+        println!("{}", determinant);
+    }
 }
 
 #[cfg(test)]
 mod determinant {
     use super::*;
+    use float_cmp::*;
 
     #[test]
     fn matrix_determinant() {
-        let mut u = Matrix::from([[1., -1.], [-1., 1.]]);
-        assert_eq!(u.determinant(), 0.0);
+        let u = Matrix::from([[1., -1.], [-1., 1.]]);
+        assert_eq!(u.determinant().unwrap(), 0.0);
 
-        let mut u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
-        assert_eq!(u.determinant(), 8.0);
+        let u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
+        assert_eq!(u.determinant().unwrap(), 8.0);
 
-        let mut u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
-        assert_eq!(u.determinant(), -174.0);
+        let u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        assert_eq!(u.determinant().unwrap(), -174.0);
 
-        let mut u = Matrix::from([
+        let u = Matrix::from([
             [8., 5., -2., 4.],
             [4., 2.5, 20., 4.],
             [8., 5., 1., 4.],
             [28., -4., 17., 1.],
         ]);
-        assert_eq!(u.determinant(), 1032.0);
+        assert_eq!(u.determinant().unwrap(), 1032.0);
+    }
+    #[test]
+    fn matrix_determinant_row_swaps() {
+        let u = Matrix::from([[0., 1.], [1., 0.]]);
+        assert_eq!(u.determinant().unwrap(), -1.0);
+
+        let u = Matrix::from([
+            [0., 2., 0., 0.],
+            [3., 0., 0., 0.],
+            [0., 0., 0., 4.],
+            [0., 0., 5., 0.],
+        ]);
+        assert_eq!(u.determinant().unwrap(), 120.0);
+    }
+
+    #[test]
+    fn matrix_determinant_large() {
+        let u = Matrix::from([
+            [2., -1., 0., 0., 0., 0.],
+            [-1., 2., -1., 0., 0., 0.],
+            [0., -1., 2., -1., 0., 0.],
+            [0., 0., -1., 2., -1., 0.],
+            [0., 0., 0., -1., 2., -1.],
+            [0., 0., 0., 0., -1., 2.],
+        ]);
+        assert_approx_eq!(f32, u.determinant().unwrap(), 7., epsilon = 1e-4);
+
+        let u: Matrix<f32> = (0..12)
+            .map(|i| (0..12).map(|j| if i == j { 2. } else { 0. }).collect())
+            .collect();
+        assert_eq!(u.determinant().unwrap(), 4096.0);
+    }
+
+    #[test]
+    fn matrix_determinant_not_square() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert!(u.determinant().is_err());
     }
 }
@@ -0,0 +1,159 @@
+use matrix::{gemm::gemm, matrix::Matrix};
+
+fn main() {
+    let a = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    let b = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+    println!("{}", a.as_view().mul_mat(&b));
+
+    let mut out = Matrix::zeros(2, 2);
+    a.mul_mat_into(&b, &mut out);
+    println!("{}", out);
+
+    gemm(0.5, &a, &b, 2., &mut out);
+    println!("{}", out);
+}
+
+#[cfg(test)]
+mod gemm {
+    use super::*;
+    use float_cmp::*;
+    use matrix::view::MatrixView;
+    use num::Complex;
+
+    /// Deterministic `m`x`n` matrix with a mix of signs and magnitudes.
+    fn sample(m: usize, n: usize, seed: usize) -> Matrix<f64> {
+        let data = (0..m * n)
+            .map(|i| ((i * 37 + seed * 11) % 23) as f64 / 4. - 2.5)
+            .collect();
+        Matrix::from_vec(m, n, data).unwrap()
+    }
+
+    /// Textbook triple loop that the blocked product is checked against.
+    fn naive(a: MatrixView<'_, f64>, b: MatrixView<'_, f64>) -> Matrix<f64> {
+        let mut res = Matrix::zeros(a.rows(), b.columns());
+        for i in 0..a.rows() {
+            for j in 0..b.columns() {
+                res[i][j] = (0..a.columns()).map(|p| a[(i, p)] * b[(p, j)]).sum();
+            }
+        }
+        res
+    }
+
+    fn assert_matrix_approx_eq(u: &Matrix<f64>, v: &Matrix<f64>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f64, *a, *b, epsilon = 1e-9));
+    }
+
+    #[test]
+    fn small_products() {
+        let u = Matrix::from([[3., -5.], [6., 8.]]);
+        let v = Matrix::from([[2., 1.], [4., 2.]]);
+        assert_eq!(u.mul_mat(&v), Matrix::from([[-14., -7.], [44., 22.]]));
+
+        let a = Matrix::from([[1., 2., 3.]]);
+        let b = Matrix::from([[4.], [5.], [6.]]);
+        assert_eq!(a.as_view().mul_mat(&b), Matrix::from([[32.]]));
+        assert_eq!(
+            b.as_view().mul_mat(&a),
+            Matrix::from([[4., 8., 12.], [5., 10., 15.], [6., 12., 18.]])
+        );
+    }
+
+    #[test]
+    fn blocked_products_match_naive() {
+        // Shapes that straddle the micro-kernel panels and the MC, KC and NC cache blocks.
+        for (m, k, n) in [
+            (1, 1, 1),
+            (3, 5, 7),
+            (4, 8, 8),
+            (9, 17, 33),
+            (67, 259, 13),
+            (5, 3, 1027),
+        ] {
+            let (a, b) = (sample(m, k, 1), sample(k, n, 2));
+            assert_matrix_approx_eq(&a.as_view().mul_mat(&b), &naive(a.as_view(), b.as_view()));
+        }
+    }
+
+    #[test]
+    fn strided_operands() {
+        let (a, b) = (sample(20, 30, 3), sample(40, 30, 4));
+        let (a, b) = (a.view(1..18, ..).strided(2, 3), b.as_view().transpose());
+        let b = b.view(..10, 5..);
+        assert_matrix_approx_eq(&a.mul_mat(b), &naive(a, b));
+
+        let mut out = Matrix::zeros(15, 40);
+        let expected = naive(a, b);
+        a.mul_mat_into(b, out.view_mut(2..11, 1..36));
+        assert_matrix_approx_eq(&out.view(2..11, 1..36).to_matrix(), &expected);
+        assert!(out.view(..2, ..).iter().all(|x| *x == 0.));
+        assert!(out.view(.., 36..).iter().all(|x| *x == 0.));
+    }
+
+    #[test]
+    fn mul_mat_into_overwrites() {
+        let (a, b) = (sample(6, 10, 5), sample(10, 9, 6));
+        let mut out = Matrix::from_vec(6, 9, vec![f64::NAN; 54]).unwrap();
+        a.mul_mat_into(&b, &mut out);
+        assert_matrix_approx_eq(&out, &naive(a.as_view(), b.as_view()));
+    }
+
+    #[test]
+    fn gemm_accumulates() {
+        let (a, b, c) = (sample(11, 7, 7), sample(7, 13, 8), sample(11, 13, 9));
+        let product = naive(a.as_view(), b.as_view());
+
+        let mut res = c.clone();
+        gemm(2., &a, &b, -0.5, &mut res);
+        let expected: Vec<f64> = (product.as_slice().iter())
+            .zip(c.as_slice())
+            .map(|(p, c)| 2. * p - 0.5 * c)
+            .collect();
+        assert_matrix_approx_eq(&res, &Matrix::from_vec(11, 13, expected).unwrap());
+
+        let mut res = c.clone();
+        gemm(1., &a, &b, 1., &mut res);
+        assert_matrix_approx_eq(&res, &(product.clone() + c.clone()));
+
+        let mut res = c.clone();
+        gemm(0., &a, &b, 3., &mut res);
+        let expected: Vec<f64> = c.as_slice().iter().map(|c| 3. * c).collect();
+        assert_matrix_approx_eq(&res, &Matrix::from_vec(11, 13, expected).unwrap());
+
+        let mut res = c.clone();
+        let empty = Matrix::<f64>::zeros(11, 0);
+        gemm(1., &empty, &Matrix::zeros(0, 13), 1., &mut res);
+        assert_eq!(res, c);
+    }
+
+    #[test]
+    fn single_and_complex_precision() {
+        let a = Matrix::<f32>::from([[1., 2., 3.], [4., 5., 6.]]);
+        let mut out = Matrix::zeros(2, 2);
+        a.mul_mat_into(a.as_view().transpose(), &mut out);
+        assert_eq!(out, Matrix::from([[14., 32.], [32., 77.]]));
+
+        let i = Complex::new(0., 1.);
+        let a = Matrix::from([[i, Complex::new(1., 0.)]]);
+        let b = Matrix::from([[i], [i]]);
+        let mut out = Matrix::from([[Complex::new(1., 1.)]]);
+        gemm(Complex::new(2., 0.), &a, &b, i, &mut out);
+        assert_eq!(out, Matrix::from([[Complex::new(-3., 3.)]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions of the matrices must agree")]
+    fn inner_dimension_mismatch() {
+        sample(2, 3, 0).as_view().mul_mat(&sample(2, 3, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "shape of the output must match the product")]
+    fn output_shape_mismatch() {
+        let mut out = Matrix::zeros(3, 3);
+        sample(2, 3, 0).mul_mat_into(&sample(3, 3, 0), &mut out);
+    }
+}
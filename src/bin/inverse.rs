@@ -1,19 +1,21 @@
 use matrix::matrix::Matrix;
 
 fn main() {
-    let mut u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
+    let u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
     if let Ok(inverse) = u.inverse() {
         println!("{}", inverse);
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod inverse {
     use super::*;
+    use float_cmp::*;
 
     #[test]
     fn matrix_inverse() {
-        let mut u = Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+        let u = Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
         if let Ok(inverse) = u.inverse() {
             assert_eq!(
                 inverse,
@@ -21,7 +23,7 @@ mod inverse {
             );
         }
 
-        let mut u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
+        let u = Matrix::from([[2., 0., 0.], [0., 2., 0.], [0., 0., 2.]]);
         if let Ok(inverse) = u.inverse() {
             assert_eq!(
                 inverse,
@@ -29,16 +31,32 @@ mod inverse {
             );
         }
 
-        let mut u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        let u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
         if let Ok(inverse) = u.inverse() {
-            assert_eq!(
-                inverse,
-                Matrix::from([
-                    [0.649425287, 0.097701149, -0.655172414],
-                    [-0.781609195, -0.126436782, 0.965517241],
-                    [0.143678161, 0.0747126454, -0.206896552]
+            inverse
+                .as_slice()
+                .iter()
+                .zip([
+                    0.649425287,
+                    0.097701149,
+                    -0.655172414,
+                    -0.781609195,
+                    -0.126436782,
+                    0.965517241,
+                    0.143678161,
+                    0.0747126454,
+                    -0.206896552,
                 ])
-            );
+                .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-6));
         }
     }
+
+    #[test]
+    fn matrix_inverse_singular() {
+        let u = Matrix::from([[1., 2.], [2., 4.]]);
+        assert!(u.inverse().is_err());
+
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert!(u.inverse().is_err());
+    }
 }
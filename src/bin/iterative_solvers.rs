@@ -0,0 +1,247 @@
+use matrix::{
+    iterative::{bicgstab, conjugate_gradient, gmres, Identity, IterativeOptions, Jacobi},
+    matrix::Matrix,
+    vector::Vector,
+};
+
+fn main() {
+    let u = Matrix::from([[4., 1., 0.], [1., 4., 1.], [0., 1., 4.]]);
+    let b = Vector::from([5., 6., 5.]);
+    let options = IterativeOptions::default();
+    if let Ok(res) = conjugate_gradient(&u, &b, &Identity, &options) {
+        println!("{}", res.solution());
+        println!("{:?}", res.residuals());
+    }
+    if let Ok(jacobi) = Jacobi::new(&u) {
+        for res in [
+            gmres(&u, &b, &jacobi, &options),
+            bicgstab(&u, &b, &jacobi, &options),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            println!("{} after {} iterations", res.solution(), res.iterations());
+        }
+    }
+}
+
+#[cfg(test)]
+mod iterative_solvers {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{error::Error, iterative::LinearOperator};
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &[f32]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    /// The 1D Poisson matrix `tridiag(-1, 2, -1)`, applied without storing it.
+    struct Poisson(usize);
+
+    impl LinearOperator<f32> for Poisson {
+        fn rows(&self) -> usize {
+            self.0
+        }
+
+        fn columns(&self) -> usize {
+            self.0
+        }
+
+        fn apply(&self, x: &Vector<f32>) -> Vector<f32> {
+            (0..self.0)
+                .map(|i| {
+                    let left = if i > 0 { x[i - 1] } else { 0. };
+                    let right = if i + 1 < self.0 { x[i + 1] } else { 0. };
+                    2. * x[i] - left - right
+                })
+                .collect()
+        }
+    }
+
+    fn nonsymmetric() -> Matrix<f32> {
+        Matrix::from([
+            [4., -1., 0., 1.],
+            [2., 5., -2., 0.],
+            [0., 1., 6., -1.],
+            [1., 0., 3., 7.],
+        ])
+    }
+
+    #[test]
+    fn conjugate_gradient_spd() {
+        let u = Matrix::from([[4., 1., 0.], [1., 4., 1.], [0., 1., 4.]]);
+        let b = Vector::from([5., 6., 5.]);
+        assert_eq!(u.apply(&Vector::from([1., 1., 1.])), b);
+        let res = conjugate_gradient(&u, &b, &Identity, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert!(res.iterations() <= 3);
+        assert_eq!(res.residuals().len(), res.iterations() + 1);
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+
+        let jacobi = Jacobi::new(&u).unwrap();
+        let res = conjugate_gradient(&u, &b, &jacobi, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+    }
+
+    #[test]
+    fn conjugate_gradient_operator() {
+        let n = 20;
+        let x: Vector<f32> = (0..n).map(|i| (i as f32 * 0.3).sin()).collect();
+        let b = Poisson(n).apply(&x);
+        let res =
+            conjugate_gradient(&Poisson(n), &b, &Identity, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert!(res.iterations() <= n);
+        let expected: Vec<f32> = x.iter().copied().collect();
+        assert_vector_approx_eq(res.solution(), &expected);
+    }
+
+    #[test]
+    fn conjugate_gradient_not_positive_definite() {
+        let u = Matrix::from([[1., 0.], [0., -1.]]);
+        let err = conjugate_gradient(
+            &u,
+            &Vector::from([0., 1.]),
+            &Identity,
+            &IterativeOptions::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Error>(),
+            Some(&Error::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn gmres_nonsymmetric() {
+        let u = nonsymmetric();
+        let b = Vector::from([4., 5., 6., 11.]);
+        let res = gmres(&u, &b, &Identity, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert!(res.iterations() <= 4);
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1., 1.]);
+
+        let options = IterativeOptions {
+            restart: 2,
+            ..Default::default()
+        };
+        let jacobi = Jacobi::new(&u).unwrap();
+        let res = gmres(&u, &b, &jacobi, &options).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1., 1.]);
+        assert!(res.residuals().windows(2).all(|r| r[1] <= r[0] * 1.0001));
+    }
+
+    #[test]
+    fn bicgstab_nonsymmetric() {
+        let u = nonsymmetric();
+        let b = Vector::from([4., 5., 6., 11.]);
+        let res = bicgstab(&u, &b, &Identity, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1., 1.]);
+
+        let jacobi = Jacobi::new(&u).unwrap();
+        let res = bicgstab(&u, &b, &jacobi, &IterativeOptions::default()).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1., 1.]);
+    }
+
+    #[test]
+    fn double_precision() {
+        let u = Matrix::from([[4., 1., 0.], [1., 4., 1.], [0., 1., 4.]]);
+        let x = Vector::from([0.1, 1. / 3., 1e-9]);
+        let b = u.mul_vec(&x);
+        let options = IterativeOptions {
+            tol: 1e-14,
+            ..Default::default()
+        };
+        for res in [
+            conjugate_gradient(&u, &b, &Identity, &options).unwrap(),
+            gmres(&u, &b, &Identity, &options).unwrap(),
+        ] {
+            assert!(res.converged());
+            res.solution()
+                .iter()
+                .zip(x.iter())
+                .for_each(|(a, b)| assert_approx_eq!(f64, *a, *b, epsilon = 1e-13));
+        }
+    }
+
+    #[test]
+    fn iteration_limit() {
+        let n = 50;
+        let b: Vector<f32> = vec![1.; n].into();
+        let options = IterativeOptions {
+            max_iterations: 3,
+            ..Default::default()
+        };
+        let res = conjugate_gradient(&Poisson(n), &b, &Identity, &options).unwrap();
+        assert!(!res.converged());
+        assert_eq!(res.iterations(), 3);
+        let res = gmres(&Poisson(n), &b, &Identity, &options).unwrap();
+        assert!(!res.converged());
+        assert_eq!(res.iterations(), 3);
+        let res = bicgstab(&Poisson(n), &b, &Identity, &options).unwrap();
+        assert!(!res.converged());
+        assert_eq!(res.iterations(), 3);
+    }
+
+    #[test]
+    fn zero_right_hand_side() {
+        let b = Vector::from([0., 0., 0., 0.]);
+        let options = IterativeOptions::default();
+        for res in [
+            gmres(&nonsymmetric(), &b, &Identity, &options).unwrap(),
+            bicgstab(&nonsymmetric(), &b, &Identity, &options).unwrap(),
+        ] {
+            assert!(res.converged());
+            assert_eq!(res.iterations(), 0);
+            assert_eq!(res.solution(), &b);
+        }
+    }
+
+    #[test]
+    fn invalid_shapes() {
+        let options = IterativeOptions::default();
+        let err = gmres(
+            &Matrix::from([[1., 2., 3.], [4., 5., 6.]]),
+            &Vector::from([1., 2.]),
+            &Identity,
+            &options,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Error>(),
+            Some(&Error::NotSquare {
+                rows: 2,
+                columns: 3
+            })
+        );
+        let err = bicgstab(
+            &nonsymmetric(),
+            &Vector::from([1., 2.]),
+            &Identity,
+            &options,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Error>(),
+            Some(&Error::DimensionMismatch {
+                expected: 4,
+                found: 2
+            })
+        );
+        for a in [
+            [[0., 1.], [1., 0.]],
+            [[1e-20, 1.], [1., 1.]],
+            [[f64::NAN, 1.], [1., 1.]],
+        ] {
+            let err = Jacobi::new(&Matrix::from(a)).unwrap_err();
+            assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[1., 2., 3.], [2., -4., 1.], [3., 1., 0.]]);
+    if let Ok(ldlt) = u.ldlt() {
+        println!("{}", ldlt.l());
+        println!("{}", ldlt.d());
+        if let Ok(x) = ldlt.solve(&Vector::from([6., -1., 4.])) {
+            println!("{}", x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod ldlt_decomposition {
+    use super::*;
+    use float_cmp::*;
+    use matrix::error::Error;
+
+    fn assert_valid_ldlt(a: &Matrix<f32>) {
+        let ldlt = a.ldlt().unwrap();
+        let p = ldlt.permutation();
+        let ldl = ldlt
+            .l()
+            .clone()
+            .mul_mat(ldlt.d())
+            .mul_mat(&ldlt.l().transpose());
+        (0..a.rows()).for_each(|i| {
+            (0..a.columns())
+                .for_each(|j| assert_approx_eq!(f32, ldl[i][j], a[p[i]][p[j]], epsilon = 1e-4))
+        });
+    }
+
+    #[test]
+    fn matrix_ldlt() {
+        assert_valid_ldlt(&Matrix::from([[4., 2.], [2., -3.]]));
+        assert_valid_ldlt(&Matrix::from([[0., 1.], [1., 0.]]));
+        assert_valid_ldlt(&Matrix::from([[1., 2., 3.], [2., -4., 1.], [3., 1., 0.]]));
+        assert_valid_ldlt(&Matrix::from([
+            [0., 1., 2., 3.],
+            [1., 0., 4., 5.],
+            [2., 4., 0., 6.],
+            [3., 5., 6., 0.],
+        ]));
+    }
+
+    #[test]
+    fn matrix_ldlt_solve() {
+        let ldlt = Matrix::from([[1., 2., 3.], [2., -4., 1.], [3., 1., 0.]])
+            .ldlt()
+            .unwrap();
+        ldlt.solve(&Vector::from([6., -1., 4.]))
+            .unwrap()
+            .iter()
+            .zip([1., 1., 1.])
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-5));
+
+        let ldlt = Matrix::from([
+            [0., 1., 2., 3.],
+            [1., 0., 4., 5.],
+            [2., 4., 0., 6.],
+            [3., 5., 6., 0.],
+        ])
+        .ldlt()
+        .unwrap();
+        ldlt.solve(&Vector::from([6., 10., 12., 14.]))
+            .unwrap()
+            .iter()
+            .zip([1., 1., 1., 1.])
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-5));
+    }
+
+    #[test]
+    fn matrix_ldlt_errors() {
+        assert!(Matrix::from([[1., 2.], [3., 4.]]).ldlt().is_err());
+        let ldlt = Matrix::from([[1., 1.], [1., 1.]]).ldlt().unwrap();
+        assert!(ldlt.solve(&Vector::from([1., 1.])).is_err());
+        assert!(ldlt.solve(&Vector::from([1., 1., 1.])).is_err());
+    }
+
+    #[test]
+    fn matrix_ldlt_numerically_singular() {
+        // vvᵀ + wwᵀ has rank 2, but rounding leaves a last pivot of about 1e-17.
+        let (v, w) = ([0.13, 0.27, 0.31], [0.3, 0.11, 0.7]);
+        let mut a = Matrix::<f64>::zeros(3, 3);
+        (0..3).for_each(|i| (0..3).for_each(|j| a[i][j] = v[i] * v[j] + w[i] * w[j]));
+        let b = Vector::from([1., 2., 3.]);
+        let ldlt = a.ldlt().unwrap();
+        assert!(ldlt.is_singular());
+        let err = ldlt.solve(&b).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        assert!(a.lu().unwrap().solve(&b).is_err());
+
+        // Eliminating the first row leaves the 2x2 block `[[0, η], [η, 0]]`, whose determinant is
+        // far below the tolerance of the original rows.
+        let eta = 2e-16;
+        let a = Matrix::from([
+            [1., 0.5, 0.25],
+            [0.5, 0.25, 0.125 + eta],
+            [0.25, 0.125 + eta, 0.0625],
+        ]);
+        let ldlt = a.ldlt().unwrap();
+        assert_ne!(ldlt.d()[2][1], 0.);
+        assert!(ldlt.is_singular());
+        assert!(ldlt.solve(&b).is_err());
+        assert!(a.lu().unwrap().is_singular());
+
+        let ldlt = Matrix::from([[0., 1.], [1., 0.]]).ldlt().unwrap();
+        assert!(!ldlt.is_singular());
+    }
+}
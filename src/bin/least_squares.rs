@@ -0,0 +1,103 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[1., 0.], [1., 1.], [1., 2.], [1., 3.]]);
+    if let Ok(lstsq) = u.lstsq(&Vector::from([1., 2.9, 5.1, 7.])) {
+        println!("{}", lstsq.solution());
+        println!("{}", lstsq.residual());
+    }
+}
+
+#[cfg(test)]
+mod least_squares {
+    use super::*;
+    use float_cmp::*;
+    use matrix::error::Error;
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &[f32]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    #[test]
+    fn matrix_lstsq_full_rank() {
+        let u = Matrix::from([[1., 0.], [1., 1.], [1., 2.], [1., 3.]]);
+        let lstsq = u.lstsq(&Vector::from([1., 3., 5., 7.])).unwrap();
+        assert_vector_approx_eq(lstsq.solution(), &[1., 2.]);
+        assert_approx_eq!(f32, lstsq.residual(), 0., epsilon = 1e-4);
+        assert_eq!(lstsq.rank(), 2);
+        assert_eq!(lstsq.singular_values().len(), 2);
+
+        let u = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        let lstsq = u.lstsq(&Vector::from([1., 1., 0.])).unwrap();
+        assert_vector_approx_eq(lstsq.solution(), &[1. / 3., 1. / 3.]);
+        assert_approx_eq!(f32, lstsq.residual(), 2. / 3f32.sqrt(), epsilon = 1e-5);
+        assert_vector_approx_eq(lstsq.singular_values(), &[3f32.sqrt(), 1.]);
+    }
+
+    #[test]
+    fn matrix_lstsq_rank_deficient() {
+        let u = Matrix::from([[1., 1.], [1., 1.], [1., 1.]]);
+        let lstsq = u.lstsq(&Vector::from([1., 2., 3.])).unwrap();
+        assert_eq!(lstsq.rank(), 1);
+        assert_vector_approx_eq(lstsq.solution(), &[1., 1.]);
+        assert_approx_eq!(f32, lstsq.residual(), 2f32.sqrt(), epsilon = 1e-5);
+
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [10., 11., 12.]]);
+        let lstsq = u.lstsq(&Vector::from([6., 15., 24., 33.])).unwrap();
+        assert_eq!(lstsq.rank(), 2);
+        assert_vector_approx_eq(lstsq.solution(), &[1., 1., 1.]);
+    }
+
+    #[test]
+    fn matrix_lstsq_tall() {
+        let m = 100_000;
+        let t = |i: usize| i as f64 / m as f64;
+        let data = (0..m).flat_map(|i| [1., t(i), t(i) * t(i)]).collect();
+        let u = Matrix::from_vec(m, 3, data).unwrap();
+        let noise = |i: usize| if i.is_multiple_of(2) { 1e-3 } else { -1e-3 };
+        let b: Vector<f64> = (0..m)
+            .map(|i| 1. - 2. * t(i) + 3. * t(i) * t(i) + noise(i))
+            .collect();
+        let lstsq = u.lstsq(&b).unwrap();
+        assert_eq!(lstsq.rank(), 3);
+        [1., -2., 3.]
+            .iter()
+            .zip(lstsq.solution().iter())
+            .for_each(|(a, b)| assert_approx_eq!(f64, *a, *b, epsilon = 1e-4));
+        assert_approx_eq!(
+            f64,
+            lstsq.residual(),
+            1e-3 * (m as f64).sqrt(),
+            epsilon = 1e-4
+        );
+    }
+
+    #[test]
+    fn matrix_lstsq_underdetermined() {
+        let u = Matrix::from([[1., 1., 0.], [0., 1., 1.]]);
+        let lstsq = u.lstsq(&Vector::from([2., 2.])).unwrap();
+        assert_eq!(lstsq.rank(), 2);
+        assert_vector_approx_eq(lstsq.solution(), &[2. / 3., 4. / 3., 2. / 3.]);
+        assert_approx_eq!(f32, lstsq.residual(), 0., epsilon = 1e-5);
+
+        assert!(u.lstsq(&Vector::from([1., 2., 3.])).is_err());
+    }
+
+    #[test]
+    fn matrix_lstsq_nan() {
+        let b = Vector::from([1., 2., 3.]);
+        for u in [
+            Matrix::from([[1., f32::NAN], [1., 1.], [1., 2.]]),
+            Matrix::from([[1., f32::NAN, 0.], [0., 1., 1.], [1., 0., 1.]]),
+            Matrix::from([[f32::NAN, 1., 0.], [0., 1., 1.]]),
+        ] {
+            let err = u
+                .lstsq(&b.iter().take(u.rows()).copied().collect())
+                .unwrap_err();
+            assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        }
+    }
+}
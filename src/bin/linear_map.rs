@@ -1,11 +1,11 @@
 use matrix::{matrix::Matrix, vector::Vector};
 
 fn main() {
-    let mut u = Matrix::from([[1., 0.], [0., 1.]]);
+    let u = Matrix::from([[1., 0.], [0., 1.]]);
     let v = Vector::from([4., 2.]);
     println!("{}", u.mul_vec(&v));
 
-    let mut u = Matrix::from([[1., 0.], [0., 1.]]);
+    let u = Matrix::from([[1., 0.], [0., 1.]]);
     let v = Matrix::from([[1., 0.], [0., 1.]]);
     println!("{}", u.mul_mat(&v));
 }
@@ -16,30 +16,30 @@ mod linear_map {
 
     #[test]
     fn matrix_linear_map() {
-        let mut u = Matrix::from([[1., 0.], [0., 1.]]);
+        let u = Matrix::from([[1., 0.], [0., 1.]]);
         let v = Vector::from([4., 2.]);
         assert_eq!(u.mul_vec(&v), Vector::from([4., 2.]));
 
-        let mut u = Matrix::from([[2., 0.], [0., 2.]]);
+        let u = Matrix::from([[2., 0.], [0., 2.]]);
         let v = Vector::from([4., 2.]);
         assert_eq!(u.mul_vec(&v), Vector::from([8., 4.]));
 
-        let mut u = Matrix::from([[2., -2.], [-2., 2.]]);
+        let u = Matrix::from([[2., -2.], [-2., 2.]]);
         let v = Vector::from([4., 2.]);
         assert_eq!(u.mul_vec(&v), Vector::from([4., -4.]));
     }
 
     #[test]
     fn matrix_multiplication() {
-        let mut u = Matrix::from([[1., 0.], [0., 1.]]);
+        let u = Matrix::from([[1., 0.], [0., 1.]]);
         let v = Matrix::from([[1., 0.], [0., 1.]]);
         assert_eq!(u.mul_mat(&v), Matrix::from([[1., 0.], [0., 1.]]));
 
-        let mut u = Matrix::from([[1., 0.], [0., 1.]]);
+        let u = Matrix::from([[1., 0.], [0., 1.]]);
         let v = Matrix::from([[2., 1.], [4., 2.]]);
         assert_eq!(u.mul_mat(&v), Matrix::from([[2., 1.], [4., 2.]]));
 
-        let mut u = Matrix::from([[3., -5.], [6., 8.]]);
+        let u = Matrix::from([[3., -5.], [6., 8.]]);
         let v = Matrix::from([[2., 1.], [4., 2.]]);
         assert_eq!(u.mul_mat(&v), Matrix::from([[-14., -7.], [44., 22.]]));
     }
@@ -0,0 +1,89 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]]);
+    if let Ok(lu) = u.lu() {
+        println!("{}", lu.l());
+        println!("{}", lu.u());
+        if let Ok(x) = lu.solve(&Vector::from([5., -2., 9.])) {
+            println!("{}", x);
+        }
+    }
+}
+
+#[cfg(test)]
+mod lu_decomposition {
+    use super::*;
+    use float_cmp::*;
+    use matrix::error::Error;
+
+    fn assert_matrix_approx_eq(u: &Matrix<f32>, v: &Matrix<f32>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-5));
+    }
+
+    #[test]
+    fn matrix_lu_factors() {
+        let a = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]]);
+        let lu = a.lu().unwrap();
+        let pa: Matrix<f32> = lu
+            .permutation()
+            .iter()
+            .map(|&i| Vector::from(&a[i]))
+            .collect();
+        assert_matrix_approx_eq(&lu.l().mul_mat(lu.u()), &pa);
+        assert_eq!(lu.permutation(), &[1, 2, 0]);
+        (0..3).for_each(|i| {
+            assert_eq!(lu.l()[i][i], 1.);
+            (0..i).for_each(|j| assert_eq!(lu.u()[i][j], 0.));
+        });
+    }
+
+    #[test]
+    fn matrix_lu_solve() {
+        let lu = Matrix::from([[2., 1., 1.], [4., -6., 0.], [-2., 7., 2.]])
+            .lu()
+            .unwrap();
+        assert_eq!(
+            lu.solve(&Vector::from([5., -2., 9.])).unwrap(),
+            Vector::from([1., 1., 2.])
+        );
+        assert_matrix_approx_eq(
+            &lu.solve_many(&Matrix::from([[5., 4.], [-2., -2.], [9., 7.]]))
+                .unwrap(),
+            &Matrix::from([[1., 1.], [1., 1.], [2., 1.]]),
+        );
+        assert!(lu.solve(&Vector::from([1., 2.])).is_err());
+    }
+
+    #[test]
+    fn matrix_lu_determinant_inverse() {
+        let a = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        let lu = a.lu().unwrap();
+        assert_approx_eq!(f32, lu.determinant(), -174., epsilon = 1e-4);
+        assert_matrix_approx_eq(&a.mul_mat(&lu.inverse().unwrap()), &Matrix::identity(3));
+    }
+
+    #[test]
+    fn matrix_lu_singular() {
+        let lu = Matrix::from([[1., 2.], [2., 4.]]).lu().unwrap();
+        assert_eq!(lu.determinant(), 0.);
+        assert!(lu.solve(&Vector::from([1., 2.])).is_err());
+        assert!(lu.inverse().is_err());
+        assert!(Matrix::from([[1., 2., 3.], [4., 5., 6.]]).lu().is_err());
+    }
+
+    #[test]
+    fn matrix_lu_nan() {
+        let mut a = Matrix::<f32>::identity(4);
+        a[2][1] = f32::NAN;
+        let err = a.lu().unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        assert!(a.determinant().is_err());
+        assert!(a.inverse().is_err());
+        assert!(a.solve(&Vector::from([1., 2., 3., 4.])).is_err());
+    }
+}
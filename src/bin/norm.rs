@@ -1,7 +1,7 @@
 use matrix::vector::Vector;
 
 fn main() {
-    let mut u = Vector::from([-5., -4., 4.]);
+    let u = Vector::from([-5., -4., 4.]);
 
     println!("{}", u.norm_1());
     println!("{}", u.norm());
@@ -9,18 +9,39 @@ fn main() {
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod norm {
     use super::*;
 
     #[test]
     fn vector_norm() {
-        let mut u = Vector::from([0., 0., 0.]);
+        let u = Vector::<f32>::from([0., 0., 0.]);
         assert_eq!((u.norm_1(), u.norm(), u.norm_inf()), (0., 0., 0.));
 
-        let mut u = Vector::from([1., 2., 3.]);
+        let u = Vector::<f32>::from([1., 2., 3.]);
         assert_eq!((u.norm_1(), u.norm(), u.norm_inf()), (6., 3.74165738, 3.));
 
-        let mut u = Vector::from([-1., -2.]);
+        let u = Vector::<f32>::from([-1., -2.]);
         assert_eq!((u.norm_1(), u.norm(), u.norm_inf()), (3., 2.236067977, 2.));
     }
+
+    #[test]
+    fn vector_norm_f64() {
+        let u = Vector::from([1e-9, 1., -0.1]);
+        assert_eq!(u.norm_1(), 1e-9 + 1. + 0.1);
+        assert_eq!(u.norm(), (1e-18f64 + 1. + 0.01).sqrt());
+        assert_eq!(u.norm_inf(), 1.);
+        assert_ne!(
+            u.norm(),
+            f64::from(Vector::<f32>::from([1e-9, 1., -0.1]).norm())
+        );
+    }
+
+    #[test]
+    fn vector_norm_nan() {
+        for u in [vec![f64::NAN], vec![1., f64::NAN], vec![f64::NAN, 1.]] {
+            let u = Vector::from(u);
+            assert!(u.norm_1().is_nan() && u.norm().is_nan() && u.norm_inf().is_nan());
+        }
+    }
 }
@@ -0,0 +1,165 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let a = Matrix::from([[1., 2.], [3., 4.]]);
+    let b = Matrix::from([[0., 1.], [1., 0.]]);
+    println!("{}", &a + &b);
+    println!("{}", &a * &b);
+    println!("{}", 2. * &a - &b);
+
+    let u = Vector::from([1., -1.]);
+    println!("{}", &a * &u);
+    println!("{}", -&u);
+}
+
+#[cfg(test)]
+mod operators {
+    use super::*;
+    use num::Complex;
+
+    fn a() -> Matrix<f64> {
+        Matrix::from([[1., 2.], [3., 4.]])
+    }
+
+    fn b() -> Matrix<f64> {
+        Matrix::from([[5., -1.], [0., 2.]])
+    }
+
+    #[test]
+    fn borrowed_matrix_arithmetic() {
+        let (a, b) = (a(), b());
+        let sum = Matrix::from([[6., 1.], [3., 6.]]);
+        assert_eq!(&a + &b, sum);
+        assert_eq!(&a + b.clone(), sum);
+        assert_eq!(a.clone() + &b, sum);
+        assert_eq!(a.clone() + b.clone(), sum);
+
+        let difference = Matrix::from([[-4., 3.], [3., 2.]]);
+        assert_eq!(&a - &b, difference);
+        assert_eq!(&a - b.clone(), difference);
+        assert_eq!(a.clone() - &b, difference);
+        assert_eq!(a.clone() - b.clone(), difference);
+
+        let mut c = a.clone();
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+        assert_eq!(a, Matrix::from([[1., 2.], [3., 4.]]));
+    }
+
+    #[test]
+    fn borrowed_vector_arithmetic() {
+        let (u, v) = (Vector::from([1., 2., 3.]), Vector::from([4., 5., 6.]));
+        assert_eq!(&u + &v, Vector::from([5., 7., 9.]));
+        assert_eq!(u.clone() + &v, &u + v.clone());
+        assert_eq!(&v - &u, Vector::from([3., 3., 3.]));
+        assert_eq!(&u * &v, Vector::from([4., 10., 18.]));
+        assert_eq!(u.clone() * v.clone(), &u * &v);
+
+        let mut w = u.clone();
+        w += &v;
+        w -= &u;
+        w *= &u;
+        assert_eq!(w, Vector::from([4., 10., 18.]));
+    }
+
+    #[test]
+    fn borrowed_left_operand_reuses_right_buffer() {
+        let (a, b) = (a(), b());
+        let rhs = b.clone();
+        let ptr = rhs.as_slice().as_ptr();
+        let sum = &a + rhs;
+        assert_eq!(sum.as_slice().as_ptr(), ptr);
+        assert_eq!(sum, &a + &b);
+        let difference = &a - sum;
+        assert_eq!(difference.as_slice().as_ptr(), ptr);
+        assert_eq!(difference, &a - &(&a + &b));
+
+        let (u, v) = (Vector::from([1., 2., 3.]), Vector::from([4., 5., 6.]));
+        for (op, expected) in [(0, &u + &v), (1, &u - &v), (2, &u * &v)] {
+            let rhs = v.clone();
+            let ptr = rhs.as_ptr();
+            let res = match op {
+                0 => &u + rhs,
+                1 => &u - rhs,
+                _ => &u * rhs,
+            };
+            assert_eq!(res.as_ptr(), ptr);
+            assert_eq!(res, expected);
+        }
+    }
+
+    #[test]
+    fn matrix_products() {
+        let (a, b) = (a(), b());
+        let product = Matrix::from([[5., 3.], [15., 5.]]);
+        assert_eq!(&a * &b, product);
+        assert_eq!(&a * b.clone(), product);
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(a.clone() * b.clone(), product);
+        assert_eq!(&a * &b, a.mul_mat(&b));
+
+        let u = Vector::from([1., -1.]);
+        let image = Vector::from([-1., -1.]);
+        assert_eq!(&a * &u, image);
+        assert_eq!(&a * u.clone(), image);
+        assert_eq!(a.clone() * &u, image);
+        assert_eq!(a.clone() * u.clone(), image);
+
+        let rectangular = Matrix::from([[1., 0., 2.]]);
+        let column = Matrix::from([[1.], [2.], [3.]]);
+        assert_eq!(&rectangular * &column, Matrix::from([[7.]]));
+        assert_eq!(&column * &rectangular * 2., 2. * (&column * &rectangular));
+    }
+
+    #[test]
+    fn scaling_and_negation() {
+        let a = a();
+        let doubled = Matrix::from([[2., 4.], [6., 8.]]);
+        assert_eq!(&a * 2., doubled);
+        assert_eq!(2. * &a, doubled);
+        assert_eq!(2. * a.clone(), doubled);
+        assert_eq!(-&a, a.clone() * -1.);
+        assert_eq!(-(-a.clone()), a);
+
+        let u = Vector::from([1f32, -2.]);
+        assert_eq!(3. * &u, Vector::from([3., -6.]));
+        assert_eq!(&u * 3., 3. * u.clone());
+        assert_eq!(-u, Vector::from([-1., 2.]));
+
+        let i = Complex::new(0., 1.);
+        let z = Matrix::from([[i, Complex::new(1., 0.)]]);
+        assert_eq!(i * &z, Matrix::from([[Complex::new(-1., 0.), i]]));
+        assert_eq!(-&z, Matrix::from([[-i, Complex::new(-1., 0.)]]));
+        let w = Vector::from([i]);
+        assert_eq!(i * w, Vector::from([Complex::new(-1., 0.)]));
+    }
+
+    #[test]
+    fn shared_receivers() {
+        let a = Matrix::from([[2., 0., 0.], [0., 3., 0.], [1., 0., 4.]]);
+        let u = Vector::from([3., -4.]);
+        assert_eq!(a.trace(), 9.);
+        assert_eq!(a.determinant().unwrap(), 24.);
+        assert_eq!(a.rank(), 3);
+        assert_eq!(&a.inverse().unwrap() * &a, Matrix::identity(3));
+        assert_eq!(a.reduced_row_echelon(), Matrix::identity(3));
+        assert_eq!(
+            a.mul_vec(&Vector::from([1., 1., 1.])),
+            Vector::from([2., 3., 5.])
+        );
+        assert_eq!((u.norm_1(), u.norm(), u.norm_inf()), (7., 5., 4.));
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions of the matrices must agree")]
+    fn product_dimension_mismatch() {
+        let _ = &Matrix::from([[1., 2.]]) * &Matrix::from([[1., 2.]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sum_shape_mismatch() {
+        let _ = &Matrix::from([[1., 2.]]) + &Matrix::from([[1.], [2.]]);
+    }
+}
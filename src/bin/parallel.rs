@@ -0,0 +1,154 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn sample(m: usize, n: usize) -> Matrix<f64> {
+    let data = (0..m * n).map(|i| ((i * 37) % 23) as f64 - 11.).collect();
+    Matrix::from_vec(m, n, data).unwrap()
+}
+
+fn main() {
+    #[cfg(feature = "parallel")]
+    {
+        matrix::parallel::set_num_threads(4);
+        println!(
+            "{} threads above {} multiply-adds",
+            matrix::parallel::num_threads(),
+            matrix::parallel::threshold()
+        );
+    }
+
+    let a = sample(300, 300);
+    let b = a.mul_mat(&a.transpose());
+    println!("{}", b.as_slice().iter().sum::<f64>());
+    println!("{}", a.mul_vec(&Vector::from(vec![1.; 300])).len());
+}
+
+#[cfg(test)]
+mod parallel {
+    use super::*;
+    use float_cmp::*;
+    use std::sync::Mutex;
+
+    /// Serializes the tests that change the global configuration.
+    static CONFIG: Mutex<()> = Mutex::new(());
+
+    /// Runs `f` once on the calling thread and once split over four threads with no threshold,
+    /// which must give exactly the same result since every element is computed the same way.
+    fn serial_and_parallel<T>(f: impl Fn() -> T) -> (T, T) {
+        let _lock = CONFIG.lock().unwrap_or_else(|e| e.into_inner());
+        #[cfg(feature = "parallel")]
+        matrix::parallel::set_num_threads(1);
+        let serial = f();
+        #[cfg(feature = "parallel")]
+        {
+            matrix::parallel::set_num_threads(4);
+            matrix::parallel::set_threshold(0);
+        }
+        let parallel = f();
+        #[cfg(feature = "parallel")]
+        {
+            matrix::parallel::set_num_threads(0);
+            matrix::parallel::set_threshold(matrix::parallel::DEFAULT_THRESHOLD);
+        }
+        (serial, parallel)
+    }
+
+    /// Symmetric positive-definite matrix, diagonally dominant so that every factorization works.
+    fn spd(n: usize) -> Matrix<f64> {
+        let mut a = sample(n, n);
+        a = a.clone() + a.transpose();
+        (0..n).for_each(|i| a[i][i] = 40. * n as f64);
+        a
+    }
+
+    fn assert_matrix_approx_eq(u: &Matrix<f64>, v: &Matrix<f64>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f64, *a, *b, epsilon = 1e-6));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn configuration() {
+        use matrix::parallel::*;
+        let _lock = CONFIG.lock().unwrap_or_else(|e| e.into_inner());
+        set_num_threads(3);
+        assert_eq!(num_threads(), 3);
+        set_num_threads(0);
+        assert!(num_threads() >= 1);
+        set_threshold(10);
+        assert_eq!(threshold(), 10);
+        set_threshold(DEFAULT_THRESHOLD);
+        assert_eq!(threshold(), DEFAULT_THRESHOLD);
+    }
+
+    #[test]
+    fn products() {
+        let (a, b) = (sample(150, 130), sample(130, 170));
+        let (serial, parallel) = serial_and_parallel(|| a.as_view().mul_mat(&b));
+        assert_eq!(serial, parallel);
+        let mut expected = Matrix::zeros(150, 170);
+        for i in 0..150 {
+            for j in 0..170 {
+                expected[i][j] = (0..130).map(|p| a[i][p] * b[p][j]).sum();
+            }
+        }
+        assert_matrix_approx_eq(&parallel, &expected);
+
+        let x: Vector<f64> = (0..130).map(|i| i as f64).collect();
+        let (serial, parallel) = serial_and_parallel(|| a.mul_vec(&x));
+        assert_eq!(serial, parallel);
+        assert_eq!(
+            parallel[1],
+            (0..130).map(|j| a[1][j] * j as f64).sum::<f64>()
+        );
+    }
+
+    #[test]
+    fn transpose_and_arithmetic() {
+        let a = sample(310, 290);
+        let (serial, parallel) = serial_and_parallel(|| a.transpose());
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel.transpose(), a);
+
+        let b = sample(310, 290).transpose().transpose() * 0.5;
+        let (serial, parallel) = serial_and_parallel(|| (a.clone() + b.clone()) - a.clone() * 2.);
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel[3][7], a[3][7] + b[3][7] - 2. * a[3][7]);
+
+        let u: Vector<f64> = (0..20000).map(|i| i as f64).collect();
+        let (serial, parallel) = serial_and_parallel(|| (u.clone() * u.clone() + u.clone()) * 2.);
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel[12345], 2. * (12345. * 12345. + 12345.));
+    }
+
+    #[test]
+    fn factorizations() {
+        let n = 90;
+        let a = spd(n);
+        let (serial, parallel) = serial_and_parallel(|| a.lu().unwrap());
+        assert_eq!(serial, parallel);
+        let mut pa = Matrix::zeros(n, n);
+        (0..n).for_each(|i| pa[i].copy_from_slice(&a[parallel.permutation()[i]]));
+        assert_matrix_approx_eq(&parallel.l().mul_mat(parallel.u()), &pa);
+
+        let (serial, parallel) = serial_and_parallel(|| a.cholesky().unwrap());
+        assert_eq!(serial, parallel);
+        assert_matrix_approx_eq(&parallel.l().mul_mat(&parallel.l().transpose()), &a);
+
+        let mut indefinite = spd(n);
+        (0..n).step_by(2).for_each(|i| indefinite[i][i] *= -1.);
+        let (serial, parallel) = serial_and_parallel(|| indefinite.ldlt().unwrap());
+        assert_eq!(serial, parallel);
+        let x: Vector<f64> = (0..n).map(|i| i as f64).collect();
+        let b = indefinite.mul_vec(&x);
+        let solution = parallel.solve(&b).unwrap();
+        (0..n).for_each(|i| assert_approx_eq!(f64, solution[i], x[i], epsilon = 1e-6));
+
+        let rect = sample(100, 70);
+        let (serial, parallel) = serial_and_parallel(|| rect.qr());
+        assert_eq!(serial, parallel);
+        assert_matrix_approx_eq(&parallel.q().mul_mat(parallel.r()), &rect);
+    }
+}
@@ -1,8 +1,8 @@
-use matrix::matrix::Matrix;
+use matrix::smatrix::SMatrix;
 
 /// This function computes a projection matrix which can be used to demonstrate the renering of 3D objects.
-fn projection(fov: f32, ratio: f32, near: f32, far: f32) -> Matrix<f32> {
-    Matrix::from([
+fn projection(fov: f32, ratio: f32, near: f32, far: f32) -> SMatrix<f32, 4, 4> {
+    SMatrix::from([
         [fov / ratio, 0., 0., 0.],
         [0., fov, 0., 0.],
         [
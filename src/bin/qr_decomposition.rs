@@ -0,0 +1,78 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+    let qr = u.qr();
+    println!("{}", qr.q());
+    println!("{}", qr.r());
+
+    let mut qr = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]).qr_givens();
+    if qr.append_row(&Vector::from([7., 8.])).is_ok() {
+        println!("{}", qr.r());
+    }
+}
+
+#[cfg(test)]
+mod qr_decomposition {
+    use super::*;
+    use float_cmp::*;
+    use matrix::qr::Qr;
+
+    fn assert_matrix_approx_eq(u: &Matrix<f32>, v: &Matrix<f32>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-3));
+    }
+
+    fn assert_valid_qr(a: &Matrix<f32>, qr: &Qr<f32>) {
+        let (q, r) = (qr.q(), qr.r());
+        assert_eq!(
+            (q.rows(), q.columns(), r.rows(), r.columns()),
+            (a.rows(), a.rows(), a.rows(), a.columns())
+        );
+        assert_matrix_approx_eq(&q.transpose().mul_mat(q), &Matrix::identity(a.rows()));
+        assert_matrix_approx_eq(&q.mul_mat(r), a);
+        (0..r.rows()).for_each(|i| (0..i.min(r.columns())).for_each(|j| assert_eq!(r[i][j], 0.)));
+    }
+
+    #[test]
+    fn matrix_qr_householder() {
+        let a = Matrix::from([[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+        let qr = a.qr();
+        assert_valid_qr(&a, &qr);
+        assert_approx_eq!(f32, qr.r()[0][0].abs(), 14., epsilon = 1e-4);
+        assert_approx_eq!(f32, qr.r()[1][1].abs(), 175., epsilon = 1e-3);
+        assert_approx_eq!(f32, qr.r()[2][2].abs(), 35., epsilon = 1e-3);
+
+        let a = Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]);
+        assert_valid_qr(&a, &a.qr());
+
+        let a = Matrix::from([[1., 2., 3., 4.], [5., 6., 7., 8.]]);
+        assert_valid_qr(&a, &a.qr());
+    }
+
+    #[test]
+    fn matrix_qr_givens() {
+        let a = Matrix::from([[12., -51., 4.], [6., 167., -68.], [-4., 24., -41.]]);
+        assert_valid_qr(&a, &a.qr_givens());
+
+        let a = Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]);
+        assert_valid_qr(&a, &a.qr_givens());
+    }
+
+    #[test]
+    fn matrix_qr_append_row() {
+        let mut qr = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]).qr();
+        qr.append_row(&Vector::from([7., 8.])).unwrap();
+        assert_valid_qr(&Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]), &qr);
+
+        let mut qr = Matrix::from([[2., 1.]]).qr_givens();
+        qr.append_row(&Vector::from([1., 3.])).unwrap();
+        qr.append_row(&Vector::from([0., 1.])).unwrap();
+        assert_valid_qr(&Matrix::from([[2., 1.], [1., 3.], [0., 1.]]), &qr);
+
+        assert!(qr.append_row(&Vector::from([1., 2., 3.])).is_err());
+    }
+}
@@ -1,14 +1,14 @@
 use matrix::matrix::Matrix;
 
 fn main() {
-    let mut u = Matrix::from([
+    let u = Matrix::from([
         [8., 5., -2., 4., 28.],
         [4., 2.5, 20., 4., -4.],
         [8., 5., 1., 4., 17.],
     ]);
     println!("{}", u.reduced_row_echelon());
 
-    let mut u = Matrix::from([[1., 2., 3., 4.], [4., 5., 6., 7.], [6., 7., 8., 9.]]);
+    let u = Matrix::from([[1., 2., 3., 4.], [4., 5., 6., 7.], [6., 7., 8., 9.]]);
     println!("{}", u.reduced_row_echelon());
 }
 
@@ -18,19 +18,19 @@ mod reduced_row_echelon {
 
     #[test]
     fn matrix_reduced_row_echelon() {
-        let mut u = Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
+        let u = Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]);
         assert_eq!(
             u.reduced_row_echelon(),
             Matrix::from([[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]])
         );
 
-        let mut u = Matrix::from([[1., 2.], [3., 4.]]);
+        let u = Matrix::from([[1., 2.], [3., 4.]]);
         assert_eq!(u.reduced_row_echelon(), Matrix::from([[1., 0.], [0., 1.]]));
 
-        let mut u = Matrix::from([[1., 2.], [2., 4.]]);
+        let u = Matrix::from([[1., 2.], [2., 4.]]);
         assert_eq!(u.reduced_row_echelon(), Matrix::from([[1., 2.], [0., 0.]]));
 
-        let mut u = Matrix::from([
+        let u = Matrix::<f32>::from([
             [8., 5., -2., 4., 28.],
             [4., 2.5, 20., 4., -4.],
             [8., 5., 1., 4., 17.],
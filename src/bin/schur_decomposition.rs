@@ -0,0 +1,125 @@
+use matrix::matrix::Matrix;
+
+fn main() {
+    let u = Matrix::from([[0., 1., 0.], [0., 0., 1.], [6., -11., 6.]]);
+    if let Ok(eigenvalues) = u.eigenvalues() {
+        println!("{:?}", eigenvalues);
+    }
+    if let Ok(schur) = Matrix::from([[1., -2., 0.], [2., 1., 0.], [1., 1., 3.]]).schur() {
+        println!("{}", schur.t());
+    }
+}
+
+#[cfg(test)]
+mod schur_decomposition {
+    use super::*;
+    use float_cmp::*;
+    use num::Complex;
+
+    fn assert_matrix_approx_eq(u: &Matrix<f32>, v: &Matrix<f32>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-3));
+    }
+
+    fn assert_eigenvalues_approx_eq(u: &[Complex<f32>], v: &[Complex<f32>]) {
+        let mut u = u.to_vec();
+        let mut v = v.to_vec();
+        let key = |c: &Complex<f32>| (c.re * 1e3).round() * 1e4 + (c.im * 1e3).round();
+        u.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+        v.sort_by(|a, b| key(a).partial_cmp(&key(b)).unwrap());
+        assert_eq!(u.len(), v.len());
+        u.iter().zip(v.iter()).for_each(|(a, b)| {
+            assert_approx_eq!(f32, a.re, b.re, epsilon = 1e-3);
+            assert_approx_eq!(f32, a.im, b.im, epsilon = 1e-3);
+        });
+    }
+
+    #[test]
+    fn matrix_hessenberg() {
+        let a = Matrix::from([
+            [4., 1., -2., 2.],
+            [1., 2., 0., 1.],
+            [-2., 0., 3., -2.],
+            [2., 1., -2., -1.],
+        ]);
+        let hessenberg = a.hessenberg().unwrap();
+        let (q, h) = (hessenberg.q(), hessenberg.h());
+        (0..4_usize).for_each(|i| (0..i.saturating_sub(1)).for_each(|j| assert_eq!(h[i][j], 0.)));
+        assert_matrix_approx_eq(&q.transpose().mul_mat(q), &Matrix::identity(4));
+        assert_matrix_approx_eq(&q.mul_mat(h).mul_mat(&q.transpose()), &a);
+        assert!(Matrix::from([[1., 2.]]).hessenberg().is_err());
+    }
+
+    #[test]
+    fn matrix_schur() {
+        let a = Matrix::from([
+            [1., 2., 3., 4., 5.],
+            [-2., 1., 0., 1., 0.],
+            [0., 3., -1., 2., 2.],
+            [1., 0., 1., 2., -3.],
+            [4., 1., 0., -1., 1.],
+        ]);
+        let schur = a.schur().unwrap();
+        let (t, z) = (schur.t(), schur.z().unwrap());
+        assert_matrix_approx_eq(&z.transpose().mul_mat(z), &Matrix::identity(5));
+        assert_matrix_approx_eq(&z.mul_mat(t).mul_mat(&z.transpose()), &a);
+        (0..5_usize).for_each(|i| (0..i.saturating_sub(1)).for_each(|j| assert_eq!(t[i][j], 0.)));
+        (1..4).for_each(|i| assert!(t[i][i - 1] == 0. || t[i + 1][i] == 0.));
+    }
+
+    #[test]
+    fn matrix_eigenvalues() {
+        let a = Matrix::<f32>::from([[0., -1.], [1., 0.]]);
+        assert_eigenvalues_approx_eq(
+            &a.eigenvalues().unwrap(),
+            &[Complex::new(0., 1.), Complex::new(0., -1.)],
+        );
+
+        let a = Matrix::<f32>::from([[0., 1., 0.], [0., 0., 1.], [6., -11., 6.]]);
+        assert_eigenvalues_approx_eq(
+            &a.eigenvalues().unwrap(),
+            &[1., 2., 3.].map(|re| Complex::new(re, 0.)),
+        );
+
+        let s = Matrix::<f32>::from([[1., 2., 0.], [0., 1., 1.], [1., 0., 2.]]);
+        let a = s
+            .clone()
+            .mul_mat(&Matrix::from([[1., -2., 0.], [2., 1., 0.], [0., 0., 3.]]));
+        let a = a.mul_mat(&s.inverse().unwrap());
+        assert_eigenvalues_approx_eq(
+            &a.eigenvalues().unwrap(),
+            &[
+                Complex::new(1., 2.),
+                Complex::new(1., -2.),
+                Complex::new(3., 0.),
+            ],
+        );
+    }
+
+    #[test]
+    fn matrix_eigenvalues_trace_determinant() {
+        let a = Matrix::from([
+            [1., 2., 3., 4., 5., 6.],
+            [-2., 1., 0., 1., 0., 2.],
+            [0., 3., -1., 2., 2., 1.],
+            [1., 0., 1., 2., -3., 0.],
+            [4., 1., 0., -1., 1., 2.],
+            [0., 2., 1., 0., 3., -2.],
+        ]);
+        let eigenvalues = a.eigenvalues().unwrap();
+        let sum = eigenvalues
+            .iter()
+            .fold(Complex::new(0., 0.), |acc, e| acc + e);
+        let product = eigenvalues
+            .iter()
+            .fold(Complex::new(1., 0.), |acc, e| acc * e);
+        assert_approx_eq!(f32, sum.re, a.trace(), epsilon = 1e-3);
+        assert_approx_eq!(f32, sum.im, 0., epsilon = 1e-3);
+        let determinant = a.determinant().unwrap();
+        assert_approx_eq!(f32, product.re / determinant, 1., epsilon = 1e-3);
+        assert_approx_eq!(f32, product.im / determinant, 0., epsilon = 1e-3);
+    }
+}
@@ -0,0 +1,233 @@
+use matrix::{
+    simd::{Isa, Kernels},
+    vector::Vector,
+};
+
+fn main() {
+    println!("{:?}", Isa::detect());
+
+    let x: Vec<f32> = (0..10).map(|i| i as f32).collect();
+    let mut y = vec![1f32; 10];
+    println!("{}", f32::dot(Isa::detect(), &x, &y));
+    f32::axpy(Isa::detect(), 2., &x, &mut y);
+    println!("{:?}", y);
+
+    let u = Vector::from([1., 2., 3., 4., 5.]) + Vector::from([5., 4., 3., 2., 1.]);
+    println!("{}", u);
+}
+
+#[cfg(test)]
+mod simd {
+    use super::*;
+    use float_cmp::*;
+    use matrix::simd::{MR, NR};
+
+    const ISAS: [Isa; 3] = [Isa::Scalar, Isa::Sse2, Isa::Avx];
+    const LENGTHS: [usize; 7] = [0, 1, 3, 7, 8, 33, 100];
+
+    type Elementwise<K> = fn(Isa, &mut [K], &[K]);
+
+    /// Deterministic operands with a mix of signs and magnitudes.
+    fn values<K: From<i8>>(len: usize, seed: i32) -> Vec<K> {
+        (0..len as i32)
+            .map(|i| K::from(((i * 37 + seed * 11) % 19 - 9) as i8))
+            .collect()
+    }
+
+    fn assert_f32_slice_approx_eq(u: &[f32], v: &[f32]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-3));
+    }
+
+    fn assert_f64_slice_approx_eq(u: &[f64], v: &[f64]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f64, *a, *b, epsilon = 1e-10));
+    }
+
+    #[test]
+    fn detect() {
+        let isa = Isa::detect();
+        assert_eq!(isa, Isa::detect());
+        #[cfg(target_arch = "x86_64")]
+        assert!(isa >= Isa::Sse2);
+        assert!(Isa::Scalar < Isa::Sse2 && Isa::Sse2 < Isa::Avx);
+    }
+
+    /// The dot products below cancel `1e8` against `-1e8` in the same AVX lane but not in the same
+    /// SSE lane, where the `1` in between is absorbed, so they tell the kernels apart.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn avx_kernels_are_reached() {
+        let avx = std::arch::is_x86_feature_detected!("avx");
+        assert_eq!(Isa::detect() == Isa::Avx, avx);
+
+        let mut x = vec![0f32; 16];
+        (x[0], x[4], x[8]) = (1e8, 1., -1e8);
+        let y = vec![1f32; 16];
+        assert_eq!(f32::dot(Isa::Sse2, &x, &y), 0.);
+        assert_eq!(f32::dot(Isa::Avx, &x, &y), if avx { 1. } else { 0. });
+
+        let mut x = vec![0f64; 8];
+        (x[0], x[2], x[4]) = (1e17, 1., -1e17);
+        let y = vec![1f64; 8];
+        assert_eq!(f64::dot(Isa::Sse2, &x, &y), 0.);
+        assert_eq!(f64::dot(Isa::Avx, &x, &y), if avx { 1. } else { 0. });
+    }
+
+    #[test]
+    fn dot_agrees() {
+        for len in LENGTHS {
+            let (x, y) = (values::<f32>(len, 1), values::<f32>(len, 2));
+            let expected = f32::dot(Isa::Scalar, &x, &y);
+            for isa in ISAS {
+                assert_approx_eq!(f32, f32::dot(isa, &x, &y), expected, epsilon = 1e-3);
+                assert_approx_eq!(f32, f32::dotc(isa, &x, &y), expected, epsilon = 1e-3);
+            }
+
+            let (x, y) = (values::<f64>(len, 3), values::<f64>(len, 4));
+            let expected = f64::dot(Isa::Scalar, &x, &y);
+            for isa in ISAS {
+                assert_approx_eq!(f64, f64::dot(isa, &x, &y), expected, epsilon = 1e-10);
+                assert_approx_eq!(f64, f64::dotc(isa, &x, &y), expected, epsilon = 1e-10);
+            }
+        }
+        assert_eq!(f32::dot(Isa::detect(), &[1., 2., 3.], &[4., 5., 6.]), 32.);
+    }
+
+    #[test]
+    fn axpy_agrees() {
+        for len in LENGTHS {
+            let x = values::<f32>(len, 5);
+            let mut expected = values::<f32>(len, 6);
+            f32::axpy(Isa::Scalar, 0.5, &x, &mut expected);
+            for isa in ISAS {
+                let mut y = values::<f32>(len, 6);
+                f32::axpy(isa, 0.5, &x, &mut y);
+                assert_f32_slice_approx_eq(&y, &expected);
+            }
+
+            let x = values::<f64>(len, 7);
+            let mut expected = values::<f64>(len, 8);
+            f64::axpy(Isa::Scalar, -1.5, &x, &mut expected);
+            for isa in ISAS {
+                let mut y = values::<f64>(len, 8);
+                f64::axpy(isa, -1.5, &x, &mut y);
+                assert_f64_slice_approx_eq(&y, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn elementwise_agrees() {
+        let f32_kernels: [Elementwise<f32>; 3] = [
+            f32::add_elementwise,
+            f32::sub_elementwise,
+            f32::mul_elementwise,
+        ];
+        let f64_kernels: [Elementwise<f64>; 3] = [
+            f64::add_elementwise,
+            f64::sub_elementwise,
+            f64::mul_elementwise,
+        ];
+        for len in LENGTHS {
+            let x = values::<f32>(len, 9);
+            for kernel in f32_kernels {
+                let mut expected = values::<f32>(len, 10);
+                kernel(Isa::Scalar, &mut expected, &x);
+                for isa in ISAS {
+                    let mut y = values::<f32>(len, 10);
+                    kernel(isa, &mut y, &x);
+                    assert_f32_slice_approx_eq(&y, &expected);
+                }
+            }
+
+            let x = values::<f64>(len, 11);
+            for kernel in f64_kernels {
+                let mut expected = values::<f64>(len, 12);
+                kernel(Isa::Scalar, &mut expected, &x);
+                for isa in ISAS {
+                    let mut y = values::<f64>(len, 12);
+                    kernel(isa, &mut y, &x);
+                    assert_f64_slice_approx_eq(&y, &expected);
+                }
+            }
+        }
+        let mut y = [1f32, 2., 3.];
+        f32::mul_elementwise(Isa::detect(), &mut y, &[2., 2., 2.]);
+        assert_eq!(y, [2., 4., 6.]);
+    }
+
+    #[test]
+    fn gemm_kernel_agrees() {
+        let ldc = NR + 3;
+        for k in [0, 1, 5, 16] {
+            let (a, b) = (values::<f32>(k * MR, 13), values::<f32>(k * NR, 14));
+            let mut expected = values::<f32>(MR * ldc, 15);
+            f32::gemm_kernel(Isa::Scalar, k, &a, &b, &mut expected, ldc);
+            for isa in ISAS {
+                let mut c = values::<f32>(MR * ldc, 15);
+                f32::gemm_kernel(isa, k, &a, &b, &mut c, ldc);
+                assert_f32_slice_approx_eq(&c, &expected);
+            }
+
+            let (a, b) = (values::<f64>(k * MR, 16), values::<f64>(k * NR, 17));
+            let mut expected = values::<f64>(MR * ldc, 18);
+            f64::gemm_kernel(Isa::Scalar, k, &a, &b, &mut expected, ldc);
+            for isa in ISAS {
+                let mut c = values::<f64>(MR * ldc, 18);
+                f64::gemm_kernel(isa, k, &a, &b, &mut c, ldc);
+                assert_f64_slice_approx_eq(&c, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_kernel_product() {
+        // A is MR x 2 and B is 2 x NR, packed column by column and row by row.
+        let a: Vec<f64> = (0..2 * MR).map(|i| (i % MR + 1) as f64).collect();
+        let b: Vec<f64> = (0..2 * NR).map(|j| (j / NR + 1) as f64).collect();
+        let mut c = vec![1.; MR * NR];
+        f64::gemm_kernel(Isa::detect(), 2, &a, &b, &mut c, NR);
+        for i in 0..MR {
+            for j in 0..NR {
+                assert_eq!(c[i * NR + j], 1. + 3. * (i + 1) as f64);
+            }
+        }
+    }
+
+    #[test]
+    fn vector_operations_use_kernels() {
+        let u: Vector<f64> = (0..37).map(|i| i as f64).collect();
+        let v: Vector<f64> = (0..37).map(|i| (37 - i) as f64).collect();
+        let expected: f64 = (0..37).map(|i| (i * (37 - i)) as f64).sum();
+        assert_eq!(u.dot(&v), expected);
+        assert!((u.clone() + v.clone()).iter().all(|x| *x == 37.));
+        assert!((u.clone() * v.clone())
+            .iter()
+            .zip(0..37)
+            .all(|(x, i)| *x == (i * (37 - i)) as f64));
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths of the operands must agree")]
+    fn dot_length_mismatch() {
+        f32::dot(Isa::detect(), &[1., 2., 3.], &[1., 2.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths of the operands must agree")]
+    fn axpy_length_mismatch() {
+        f64::axpy(Isa::detect(), 1., &[1., 2.], &mut [1., 2., 3.]);
+    }
+
+    #[test]
+    #[should_panic(expected = "packed panels are too short")]
+    fn gemm_kernel_short_panel() {
+        let mut c = vec![0f32; MR * NR];
+        f32::gemm_kernel(Isa::detect(), 2, &[0.; MR], &[0.; 2 * NR], &mut c, NR);
+    }
+}
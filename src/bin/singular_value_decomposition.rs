@@ -0,0 +1,108 @@
+use matrix::matrix::Matrix;
+
+fn main() {
+    let u = Matrix::from([[3., 2., 2.], [2., 3., -2.]]);
+    let svd = u.svd();
+    println!("{}", svd.u());
+    println!("{}", svd.singular_values());
+    println!("{}", svd.vt());
+    println!("{}", u.pinv());
+}
+
+#[cfg(test)]
+mod singular_value_decomposition {
+    use super::*;
+    use float_cmp::*;
+
+    fn assert_matrix_approx_eq(u: &Matrix<f32>, v: &Matrix<f32>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    fn assert_valid_svd(a: &Matrix<f32>) {
+        let svd = a.svd();
+        let k = a.rows().min(a.columns());
+        let (u, s, vt) = (svd.u(), svd.singular_values(), svd.vt());
+        assert_eq!(
+            (u.rows(), u.columns(), s.len(), vt.rows(), vt.columns()),
+            (a.rows(), k, k, k, a.columns())
+        );
+        assert!(s.windows(2).all(|w| w[0] >= w[1]));
+        assert_matrix_approx_eq(&u.transpose().mul_mat(u), &Matrix::identity(k));
+        assert_matrix_approx_eq(&vt.mul_mat(&vt.transpose()), &Matrix::identity(k));
+
+        let mut us = u.clone();
+        (0..a.rows()).for_each(|i| (0..k).for_each(|j| us[i][j] *= s[j]));
+        assert_matrix_approx_eq(&us.mul_mat(vt), a);
+    }
+
+    #[test]
+    fn matrix_svd() {
+        assert_valid_svd(&Matrix::from([[3., 2., 2.], [2., 3., -2.]]));
+        assert_valid_svd(&Matrix::from([[1., 2.], [3., 4.], [5., 6.], [7., 8.]]));
+        assert_valid_svd(&Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]));
+        assert_valid_svd(&Matrix::from([[1., 1.], [1., 1.], [0., 0.]]));
+
+        let s = Matrix::<f32>::from([[3., 2., 2.], [2., 3., -2.]]).singular_values();
+        assert_approx_eq!(f32, s[0], 5., epsilon = 1e-5);
+        assert_approx_eq!(f32, s[1], 3., epsilon = 1e-5);
+    }
+
+    #[test]
+    fn matrix_pinv() {
+        let a = Matrix::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let pinv = a.pinv();
+        assert_eq!((pinv.rows(), pinv.columns()), (2, 3));
+        assert_matrix_approx_eq(&pinv.mul_mat(&a), &Matrix::identity(2));
+
+        let a = Matrix::from([[1., 2., 3.], [2., 4., 6.], [1., 0., 1.]]);
+        let pinv = a.pinv();
+        assert_matrix_approx_eq(&a.mul_mat(&pinv).mul_mat(&a), &a);
+        assert_matrix_approx_eq(&pinv.mul_mat(&a).mul_mat(&pinv), &pinv);
+
+        let a = Matrix::from([[4., 7.], [2., 6.]]);
+        assert_matrix_approx_eq(&a.pinv(), &a.inverse().unwrap());
+    }
+
+    #[test]
+    fn matrix_svd_numerically_rank_deficient() {
+        // vvᵀ + wwᵀ, whose third singular value is rounding noise.
+        let (v, w) = ([0.13, 0.27, 0.31], [0.3, 0.11, 0.7]);
+        let mut a = Matrix::<f64>::zeros(3, 3);
+        (0..3).for_each(|i| (0..3).for_each(|j| a[i][j] = v[i] * v[j] + w[i] * w[j]));
+        let svd = a.svd();
+        assert!(svd.singular_values()[2] <= svd.default_tolerance());
+        let utu = svd.u().transpose().mul_mat(svd.u());
+        (0..3).for_each(|i| {
+            (0..3).for_each(|j| {
+                let expected = if i == j { 1. } else { 0. };
+                assert_approx_eq!(f64, utu[i][j], expected, epsilon = 1e-12)
+            })
+        });
+        assert_eq!(a.rank(), 2);
+    }
+
+    #[test]
+    fn matrix_svd_nan() {
+        let a = Matrix::from([[1., f32::NAN], [f32::NAN, 1.]]);
+        let svd = a.svd();
+        assert!(svd.singular_values().iter().any(|s| s.is_nan()));
+        assert_eq!(a.singular_values().len(), 2);
+        assert_eq!(a.rank_with_tol(0.), svd.rank(0.));
+        assert_eq!((a.pinv().rows(), a.pinv().columns()), (2, 2));
+    }
+
+    #[test]
+    fn matrix_rank_with_tol() {
+        let a = Matrix::from([[1., 1.], [1., 1.0001]]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.rank_with_tol(1e-3), 1);
+
+        let a = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(Matrix::from([[0., 0.], [0., 0.]]).rank_with_tol(0.), 0);
+    }
+}
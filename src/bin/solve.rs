@@ -0,0 +1,96 @@
+use matrix::{matrix::Matrix, vector::Vector};
+
+fn main() {
+    let u = Matrix::from([[3., 2., -1.], [2., -2., 4.], [-1., 0.5, -1.]]);
+    if let Ok(x) = u.solve(&Vector::from([1., -2., 0.])) {
+        println!("{}", x);
+    }
+}
+
+#[cfg(test)]
+mod solve {
+    use super::*;
+    use float_cmp::*;
+    use matrix::error::Error;
+
+    fn assert_vector_approx_eq(u: &[f32], v: &[f32]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-5));
+    }
+
+    fn error_of<T: std::fmt::Debug>(res: anyhow::Result<T>) -> Error {
+        *res.unwrap_err().downcast_ref::<Error>().unwrap()
+    }
+
+    #[test]
+    fn matrix_solve() {
+        let u = Matrix::from([[3., 2., -1.], [2., -2., 4.], [-1., 0.5, -1.]]);
+        assert_vector_approx_eq(
+            &u.solve(&Vector::from([1., -2., 0.])).unwrap(),
+            &[1., -2., -2.],
+        );
+
+        let u = Matrix::from([[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]]);
+        assert_vector_approx_eq(
+            &u.solve(&Vector::from([0., 6., 39.])).unwrap(),
+            &[1., 1., 1.],
+        );
+
+        let u = Matrix::from([[1., 2.], [2., 1.]]);
+        assert_vector_approx_eq(&u.solve(&Vector::from([3., 3.])).unwrap(), &[1., 1.]);
+    }
+
+    #[test]
+    fn matrix_solve_many() {
+        let u = Matrix::from([[2., 1.], [1., 3.]]);
+        let x = u.solve_many(&Matrix::from([[3., 1.], [4., -2.]])).unwrap();
+        assert_vector_approx_eq(&x[0], &[1., 1.]);
+        assert_vector_approx_eq(&x[1], &[1., -1.]);
+
+        let u = Matrix::from([[0., 1.], [1., 0.]]);
+        let x = u.solve_many(&Matrix::from([[1., 2.], [3., 4.]])).unwrap();
+        assert_eq!(x, Matrix::from([[3., 4.], [1., 2.]]));
+    }
+
+    #[test]
+    fn matrix_solve_errors() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(
+            error_of(u.solve(&Vector::from([1., 2.]))),
+            Error::NotSquare {
+                rows: 2,
+                columns: 3
+            }
+        );
+
+        let u = Matrix::from([[1., 2.], [3., 4.]]);
+        assert_eq!(
+            error_of(u.solve(&Vector::from([1., 2., 3.]))),
+            Error::DimensionMismatch {
+                expected: 2,
+                found: 3
+            }
+        );
+        assert_eq!(
+            error_of(u.solve_many(&Matrix::from([[1.], [2.], [3.]]))),
+            Error::DimensionMismatch {
+                expected: 2,
+                found: 3
+            }
+        );
+
+        let u = Matrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(error_of(u.solve(&Vector::from([1., 2.]))), Error::Singular);
+
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+        assert_eq!(
+            error_of(u.solve(&Vector::from([1., 2., 3.]))),
+            Error::Singular
+        );
+
+        let u = Matrix::from([[1., 1.], [1., 1.]]);
+        assert_eq!(error_of(u.solve(&Vector::from([1., 2.]))), Error::Singular);
+    }
+}
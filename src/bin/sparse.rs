@@ -0,0 +1,159 @@
+use matrix::{
+    iterative::{conjugate_gradient, Identity, IterativeOptions},
+    sparse::Coo,
+    vector::Vector,
+};
+
+/// Assembles the stiffness matrix of `n` linear elements on a 1D mesh with both ends fixed, adding
+/// the contribution of every element to the shared nodes.
+fn stiffness(n: usize) -> Coo<f32> {
+    let mut coo = Coo::new(n - 1, n - 1);
+    for e in 0..n {
+        let nodes = [e.checked_sub(1), (e < n - 1).then_some(e)];
+        for (a, i) in nodes.iter().enumerate() {
+            for (b, j) in nodes.iter().enumerate() {
+                if let (Some(i), Some(j)) = (i, j) {
+                    coo.push(*i, *j, if a == b { 1. } else { -1. });
+                }
+            }
+        }
+    }
+    coo
+}
+
+fn main() {
+    let a = stiffness(6).to_csr();
+    println!("{}", a.to_matrix());
+    let b = Vector::from([1., 1., 1., 1., 1.]);
+    if let Ok(res) = conjugate_gradient(&a, &b, &Identity, &IterativeOptions::default()) {
+        println!("{}", res.solution());
+    }
+}
+
+#[cfg(test)]
+mod sparse {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{
+        matrix::Matrix,
+        sparse::{Csc, Csr},
+    };
+
+    fn sample() -> Matrix<f32> {
+        Matrix::from([[1., 0., 2., 0.], [0., 0., 3., 0.], [4., 5., 0., 6.]])
+    }
+
+    #[test]
+    fn sparse_coo() {
+        let mut coo = Coo::new(3, 4);
+        coo.extend([(2, 3, 6.), (0, 2, 2.), (2, 0, 4.), (1, 2, 3.), (2, 1, 5.)]);
+        coo.push(0, 0, 0.5);
+        coo.push(0, 0, 0.5);
+        assert_eq!(coo.nnz(), 7);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 6);
+        assert_eq!(csr.indptr(), [0, 2, 3, 6]);
+        assert_eq!(csr.indices(), [0, 2, 2, 0, 1, 3]);
+        assert_eq!(csr.values(), [1., 2., 3., 4., 5., 6.]);
+        assert_eq!(csr.to_matrix(), sample());
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.indptr(), [0, 2, 3, 5, 6]);
+        assert_eq!(csc.indices(), [0, 2, 2, 0, 1, 2]);
+        assert_eq!(csc.to_matrix(), sample());
+        assert_eq!((csc.get(1, 2), csc.get(1, 1)), (3., 0.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sparse_coo_out_of_bounds() {
+        Coo::new(2, 2).push(2, 0, 1.);
+    }
+
+    #[test]
+    fn sparse_dense_conversions() {
+        let u = sample();
+        let csr = Csr::from(&u);
+        let csc = Csc::from(&u);
+        assert_eq!(csr.nnz(), 6);
+        assert_eq!(Matrix::from(&csr), u);
+        assert_eq!(Matrix::from(&csc), u);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(csr.row(2), ([0, 1, 3].as_slice(), [4., 5., 6.].as_slice()));
+        assert_eq!(csc.col(2), ([0, 1].as_slice(), [2., 3.].as_slice()));
+        assert_eq!(
+            Csr::from(u.view(1.., 2..)).to_matrix(),
+            u.view(1.., 2..).to_matrix()
+        );
+    }
+
+    #[test]
+    fn sparse_transpose() {
+        let u = sample();
+        let csr = Csr::from(&u);
+        assert_eq!(csr.transpose().to_matrix(), u.transpose());
+        assert_eq!(Csc::from(&u).transpose().to_matrix(), u.transpose());
+        assert_eq!(csr.transpose().transpose(), csr);
+    }
+
+    #[test]
+    fn sparse_products() {
+        let u = sample();
+        let x = Vector::from([1., -1., 2., 0.5]);
+        assert_eq!(Csr::from(&u).mul_vec(&x), u.mul_vec(&x));
+        assert_eq!(Csc::from(&u).mul_vec(&x), u.mul_vec(&x));
+
+        let v = Matrix::from([[1., 0.], [2., 1.], [0., 3.], [1., 1.]]);
+        assert_eq!(Csr::from(&u).mul_mat(&v), u.mul_mat(&v));
+        assert_eq!(Csc::from(&u).mul_mat(&v), u.mul_mat(&v));
+        assert_eq!(
+            Csr::from(&u).mul_mat(v.view(.., 1..)),
+            u.mul_mat(v.view(.., 1..))
+        );
+    }
+
+    #[test]
+    fn sparse_add() {
+        let u = sample();
+        let v = Matrix::from([[0., 1., -2., 0.], [0., 0., 0., 0.], [1., 0., 0., 0.]]);
+        let sum = Csr::from(&u) + Csr::from(&v);
+        assert_eq!(sum.to_matrix(), u.clone() + v.clone());
+        assert_eq!(sum.nnz(), 7);
+        let sum = Csc::from(&u) + Csc::from(&v);
+        assert_eq!(sum.to_matrix(), u + v);
+    }
+
+    #[test]
+    fn sparse_default() {
+        let csr = Csr::<f32>::default();
+        assert_eq!(csr, Coo::new(0, 0).to_csr());
+        assert_eq!(csr.indptr(), [0]);
+        assert_eq!(csr.transpose(), csr);
+        assert_eq!(csr.clone() + csr.clone(), csr);
+        assert_eq!(csr.to_csc(), Csc::default());
+        assert_eq!(csr.to_matrix(), Matrix::zeros(0, 0));
+        assert_eq!(csr.mul_vec(&Vector::from(vec![])).len(), 0);
+
+        let csc = Csc::<f32>::default();
+        assert_eq!(csc, Coo::new(0, 0).to_csc());
+        assert_eq!(csc.conjugate_transpose(), csc);
+        assert_eq!(csc.clone() + csc.clone(), csc);
+        assert_eq!(csc.to_csr(), Csr::default());
+    }
+
+    #[test]
+    fn sparse_conjugate_gradient() {
+        let a = stiffness(6);
+        assert_eq!(a.to_csr().nnz(), 13);
+        let b = Vector::from([1., 1., 1., 1., 1.]);
+        let options = IterativeOptions::default();
+        let res = conjugate_gradient(&a.to_csc(), &b, &Identity, &options).unwrap();
+        assert!(res.converged());
+        res.solution()
+            .iter()
+            .zip([2.5, 4., 4.5, 4., 2.5])
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-4));
+    }
+}
@@ -0,0 +1,233 @@
+use matrix::{
+    ordering::Ordering,
+    sparse::{Coo, Csc},
+    sparse_cholesky::SparseCholesky,
+    sparse_lu::SparseLu,
+    vector::Vector,
+};
+
+/// Assembles the five-point Laplacian on a `g`x`g` grid, optionally with a convection term that
+/// makes it unsymmetric.
+fn laplacian(g: usize, convection: f32) -> Csc<f32> {
+    let mut coo = Coo::new(g * g, g * g);
+    for i in 0..g {
+        for j in 0..g {
+            let k = i * g + j;
+            coo.push(k, k, 4.);
+            if j + 1 < g {
+                coo.push(k, k + 1, -1. + convection);
+                coo.push(k + 1, k, -1. - convection);
+            }
+            if i + 1 < g {
+                coo.push(k, k + g, -1.);
+                coo.push(k + g, k, -1.);
+            }
+        }
+    }
+    coo.to_csc()
+}
+
+fn main() {
+    let a = laplacian(3, 0.);
+    let b = Vector::from([1., 1., 1., 1., 1., 1., 1., 1., 1.]);
+    if let Ok(cholesky) = SparseCholesky::new(&a, Ordering::default()) {
+        println!("{}", cholesky.solve(&b).unwrap());
+    }
+    if let Ok(lu) = SparseLu::new(&laplacian(3, 0.5), Ordering::default()) {
+        println!("{}", lu.solve(&b).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod sparse_solvers {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{
+        error::Error, matrix::Matrix, sparse_cholesky::SymbolicCholesky, sparse_lu::SymbolicLu,
+    };
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &Vector<f32>) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v.iter())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    fn rhs(n: usize) -> Vector<f32> {
+        (0..n).map(|i| (i % 5) as f32 - 2.).collect()
+    }
+
+    /// Star graph centred on node 0: without reordering its Cholesky factor is completely dense.
+    fn arrow(n: usize) -> Csc<f32> {
+        let mut coo = Coo::new(n, n);
+        coo.push(0, 0, n as f32);
+        for i in 1..n {
+            coo.extend([(i, i, 2.), (0, i, 1.), (i, 0, 1.)]);
+        }
+        coo.to_csc()
+    }
+
+    #[test]
+    fn sparse_ordering() {
+        let a = arrow(6);
+        let permutation = Ordering::ReverseCuthillMcKee.permutation(&a);
+        let mut sorted = permutation.clone();
+        sorted.sort();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(permutation[4], 0);
+        assert_eq!(Ordering::Natural.permutation(&a), [0, 1, 2, 3, 4, 5]);
+
+        let natural = SymbolicCholesky::new(&a, Ordering::Natural).unwrap();
+        let rcm = SymbolicCholesky::new(&a, Ordering::ReverseCuthillMcKee).unwrap();
+        assert_eq!(natural.nnz(), 21);
+        assert_eq!(rcm.nnz(), 11);
+        assert_eq!(natural.parent()[0], Some(1));
+    }
+
+    #[test]
+    fn sparse_cholesky() {
+        let a = laplacian(4, 0.);
+        let b = rhs(16);
+        let expected = a.to_matrix().solve(&b).unwrap();
+        for ordering in [Ordering::Natural, Ordering::ReverseCuthillMcKee] {
+            let cholesky = SparseCholesky::new(&a, ordering).unwrap();
+            assert_vector_approx_eq(&cholesky.solve(&b).unwrap(), &expected);
+            assert_eq!(cholesky.l().nnz(), cholesky.symbolic().nnz());
+        }
+
+        let cholesky = SparseCholesky::new(&arrow(6), Ordering::default()).unwrap();
+        let l = cholesky.l().to_matrix();
+        let mut permuted = Matrix::zeros(6, 6);
+        let p = cholesky.symbolic().permutation();
+        (0..6).for_each(|i| (0..6).for_each(|j| permuted[i][j] = arrow(6).get(p[i], p[j])));
+        l.mul_mat(&l.transpose())
+            .as_slice()
+            .iter()
+            .zip(permuted.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-5));
+    }
+
+    #[test]
+    fn sparse_cholesky_refactorize() {
+        let a = laplacian(3, 0.);
+        let symbolic = SymbolicCholesky::new(&a, Ordering::default()).unwrap();
+        let b = rhs(9);
+        let x = symbolic.factorize(&a).unwrap().solve(&b).unwrap();
+
+        let mut scaled = Coo::new(9, 9);
+        (0..9).for_each(|j| {
+            let (rows, values) = a.col(j);
+            rows.iter()
+                .zip(values)
+                .for_each(|(&i, &v)| scaled.push(i, j, 2. * v));
+        });
+        let y = symbolic
+            .factorize(&scaled.to_csc())
+            .unwrap()
+            .solve(&b)
+            .unwrap();
+        x.iter()
+            .zip(y.iter())
+            .for_each(|(x, y)| assert_approx_eq!(f32, *x, 2. * y, epsilon = 1e-5));
+
+        let err = symbolic.factorize(&laplacian(2, 0.)).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::PatternMismatch));
+    }
+
+    #[test]
+    fn sparse_cholesky_errors() {
+        let err = SparseCholesky::new(&laplacian(3, 0.5), Ordering::default()).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotSymmetric));
+
+        let indefinite = Csc::from(&Matrix::from([[1., 2.], [2., 1.]]));
+        let err = SparseCholesky::new(&indefinite, Ordering::default()).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Error>(),
+            Some(&Error::NotPositiveDefinite)
+        );
+
+        let rectangular = Csc::from(&Matrix::from([[1., 0., 0.], [0., 1., 0.]]));
+        assert!(SymbolicCholesky::new(&rectangular, Ordering::default()).is_err());
+
+        let cholesky = SparseCholesky::new(&laplacian(2, 0.), Ordering::default()).unwrap();
+        assert!(cholesky.solve(&Vector::from([1., 2.])).is_err());
+    }
+
+    #[test]
+    fn sparse_cholesky_rounding() {
+        let mut coo = Coo::new(3, 3);
+        coo.extend([(0, 0, 4.), (1, 1, 4.), (2, 2, 4.), (0, 1, -1.), (1, 2, 0.3)]);
+        coo.extend([(1, 0, -1. + f32::EPSILON / 2.), (2, 1, 0.1 + 0.2)]);
+        let a = coo.to_csc();
+        assert!(a.is_hermitian() && a.to_csr().is_hermitian());
+        assert!(a.to_matrix().cholesky().is_ok());
+        let b = Vector::from([3., 3.3, 4.3]);
+        let x = SparseCholesky::new(&a, Ordering::default())
+            .unwrap()
+            .solve(&b)
+            .unwrap();
+        x.iter()
+            .for_each(|x| assert_approx_eq!(f32, *x, 1., epsilon = 1e-5));
+        assert!(!laplacian(3, 0.5).is_hermitian());
+    }
+
+    #[test]
+    fn sparse_lu() {
+        let a = laplacian(4, 0.5);
+        let b = rhs(16);
+        let expected = a.to_matrix().solve(&b).unwrap();
+        for ordering in [Ordering::Natural, Ordering::ReverseCuthillMcKee] {
+            let lu = SparseLu::new(&a, ordering).unwrap();
+            assert_vector_approx_eq(&lu.solve(&b).unwrap(), &expected);
+        }
+
+        // Needs row exchanges: the diagonal is zero.
+        let a = Matrix::from([
+            [0., 1., 0., 2.],
+            [3., 0., 1., 0.],
+            [0., 2., 0., 1.],
+            [1., 0., 4., 0.],
+        ]);
+        let lu = SparseLu::new(&Csc::from(&a), Ordering::default()).unwrap();
+        let b = Vector::from([1., 2., 3., 4.]);
+        assert_vector_approx_eq(&lu.solve(&b).unwrap(), &a.solve(&b).unwrap());
+
+        // PAQ = LU
+        let (p, q) = (lu.row_permutation(), lu.symbolic().column_permutation());
+        let mut paq = Matrix::zeros(4, 4);
+        (0..4).for_each(|i| (0..4).for_each(|j| paq[i][j] = a[p[i]][q[j]]));
+        let product = lu.l().to_matrix().mul_mat(&lu.u().to_matrix());
+        product
+            .as_slice()
+            .iter()
+            .zip(paq.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-5));
+    }
+
+    #[test]
+    fn sparse_lu_refactorize() {
+        let a = laplacian(3, 0.5);
+        let symbolic = SymbolicLu::new(&a, Ordering::default()).unwrap();
+        let b = rhs(9);
+        let x = symbolic.factorize(&a).unwrap().solve(&b).unwrap();
+        assert_vector_approx_eq(&x, &a.to_matrix().solve(&b).unwrap());
+
+        // The convection term only changes the values, not the pattern.
+        let a = laplacian(3, -0.25);
+        let x = symbolic.factorize(&a).unwrap().solve(&b).unwrap();
+        assert_vector_approx_eq(&x, &a.to_matrix().solve(&b).unwrap());
+
+        let err = symbolic.factorize(&laplacian(2, 0.5)).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::PatternMismatch));
+    }
+
+    #[test]
+    fn sparse_lu_singular() {
+        let a = Csc::from(&Matrix::from([[1., 2., 0.], [2., 4., 0.], [0., 0., 1.]]));
+        let err = SparseLu::new(&a, Ordering::Natural).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+
+        let a = Csc::from(&Matrix::from([[1., 0.], [1., 0.]]));
+        assert!(SparseLu::new(&a, Ordering::default()).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use matrix::{matrix::Matrix, smatrix::SMatrix, svector::SVector};
+
+fn main() {
+    let rotation = SMatrix::from([[0., -1., 0.], [1., 0., 0.], [0., 0., 1.]]);
+    let u = SVector::from([1., 2., 3.]);
+    println!("{}", rotation.mul_vec(&u));
+    println!("{}", Matrix::from(rotation.mul_mat(&rotation.transpose())));
+}
+
+#[cfg(test)]
+mod static_matrix {
+    use super::*;
+    use float_cmp::*;
+    use matrix::vector::Vector;
+
+    #[test]
+    fn svector_arithmetic() {
+        let u = SVector::from([1., 2., 3.]);
+        let v = SVector::from([4., 5., 6.]);
+        assert_eq!(u + v, SVector::from([5., 7., 9.]));
+        assert_eq!(v - u, SVector::from([3., 3., 3.]));
+        assert_eq!(u * 2., SVector::from([2., 4., 6.]));
+        assert_eq!(-u, SVector::from([-1., -2., -3.]));
+        assert_eq!(u.dot(&v), 32.);
+        assert_eq!(SVector::from([3., 4.]).norm(), 5.);
+        assert_eq!(u.cross(&v), SVector::from([-3., 6., -3.]));
+        assert_eq!(u.len(), 3);
+    }
+
+    #[test]
+    fn smatrix_arithmetic() {
+        let u = SMatrix::from([[1., 2.], [3., 4.]]);
+        let v = SMatrix::from([[4., 3.], [2., 1.]]);
+        assert_eq!(u + v, SMatrix::from([[5., 5.], [5., 5.]]));
+        assert_eq!(u - v, SMatrix::from([[-3., -1.], [1., 3.]]));
+        assert_eq!(u * 0.5, SMatrix::from([[0.5, 1.], [1.5, 2.]]));
+        assert_eq!(u[(1, 0)], 3.);
+        assert_eq!(u[1], [3., 4.]);
+        assert_eq!(u.trace(), 5.);
+        assert_eq!(SMatrix::<f32, 2, 2>::identity().mul_mat(&u), u);
+    }
+
+    #[test]
+    fn smatrix_products() {
+        let u = SMatrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let t: SMatrix<f32, 3, 2> = u.transpose();
+        assert_eq!(t, SMatrix::from([[1., 4.], [2., 5.], [3., 6.]]));
+        assert_eq!(u.mul_mat(&t), SMatrix::from([[14., 32.], [32., 77.]]));
+        assert_eq!(
+            u.mul_vec(&SVector::from([1., 0., -1.])),
+            SVector::from([-2., -2.])
+        );
+    }
+
+    #[test]
+    fn smatrix_determinant_inverse() {
+        let u = SMatrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
+        assert_approx_eq!(f32, u.determinant(), -174., epsilon = 1e-4);
+        let identity = u.mul_mat(&u.inverse().unwrap());
+        identity
+            .data
+            .iter()
+            .flatten()
+            .zip([1., 0., 0., 0., 1., 0., 0., 0., 1.])
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, b, epsilon = 1e-5));
+        assert_eq!(SMatrix::from([[0., 1.], [1., 0.]]).determinant(), -1.);
+
+        let singular = SMatrix::from([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.determinant(), 0.);
+        assert!(singular.inverse().is_err());
+
+        let nearly_singular = [[1., 2., 3.], [4., 5., 6.], [7., 8., 9. + 1e-15]];
+        assert!(Matrix::from(nearly_singular).inverse().is_err());
+        assert!(SMatrix::from(nearly_singular).inverse().is_err());
+        assert_eq!(SMatrix::from(nearly_singular).determinant(), 0.);
+        let tiny = 2f64.powi(-900);
+        let scaled = SMatrix::from([[tiny, 0.], [0., tiny]]);
+        assert_eq!(
+            scaled.inverse().unwrap(),
+            SMatrix::from([[1. / tiny, 0.], [0., 1. / tiny]])
+        );
+
+        let nan = SMatrix::from([[1., 0.], [f64::NAN, 1.]]);
+        assert!(nan.determinant().is_nan());
+        assert!(nan.inverse().is_err());
+    }
+
+    #[test]
+    fn smatrix_conversions() {
+        let u = SMatrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let dynamic = Matrix::from(u);
+        assert_eq!(dynamic, Matrix::from([[1., 2., 3.], [4., 5., 6.]]));
+        assert_eq!(SMatrix::try_from(&dynamic).unwrap(), u);
+        assert_eq!(
+            SMatrix::<f32, 2, 2>::try_from(dynamic.view(.., 1..)).unwrap(),
+            SMatrix::from([[2., 3.], [5., 6.]])
+        );
+        assert!(SMatrix::<f32, 3, 2>::try_from(&dynamic).is_err());
+        assert!(SMatrix::<f32, 2, 2>::try_from(&dynamic).is_err());
+
+        let v = SVector::from([1., 2., 3.]);
+        let dynamic = Vector::from(v);
+        assert_eq!(dynamic, Vector::from([1., 2., 3.]));
+        assert_eq!(SVector::try_from(&dynamic).unwrap(), v);
+        assert!(SVector::<f32, 2>::try_from(&dynamic).is_err());
+    }
+}
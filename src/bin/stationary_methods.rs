@@ -0,0 +1,175 @@
+use matrix::{
+    matrix::Matrix,
+    stationary::{gauss_seidel, jacobi, sor, StationaryOptions},
+    vector::Vector,
+};
+
+fn main() {
+    let u = Matrix::from([[4., -1., 0.], [-1., 4., -1.], [0., -1., 4.]]);
+    let b = Vector::from([3., 2., 3.]);
+    let options = StationaryOptions::default();
+    if let Ok(res) = jacobi(&u, &b, &options, |k, _, r| println!("{}: {}", k, r)) {
+        println!("{}", res.solution());
+    }
+    for res in [
+        gauss_seidel(&u, &b, &options, |_, _, _| {}),
+        sor(&u, &b, 1.05, &options, |_, _, _| {}),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        println!("{} after {} iterations", res.solution(), res.iterations());
+    }
+}
+
+#[cfg(test)]
+mod stationary_methods {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{error::Error, stationary::Norm};
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &[f32]) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v)
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    fn poisson(n: usize) -> Matrix<f32> {
+        let mut u = Matrix::zeros(n, n);
+        for i in 0..n {
+            u[i][i] = 2.;
+            if i > 0 {
+                u[i][i - 1] = -1.;
+                u[i - 1][i] = -1.;
+            }
+        }
+        u
+    }
+
+    #[test]
+    fn diagonally_dominant() {
+        let u = Matrix::from([[4., -1., 0.], [-1., 4., -1.], [0., -1., 4.]]);
+        let b = Vector::from([3., 2., 3.]);
+        let options = StationaryOptions::default();
+        let res = jacobi(&u, &b, &options, |_, _, _| {}).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+        let jacobi_iterations = res.iterations();
+
+        let res = gauss_seidel(&u, &b, &options, |_, _, _| {}).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+        assert!(res.iterations() < jacobi_iterations);
+
+        let res = sor(&u, &b, 0.9, &options, |_, _, _| {}).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+    }
+
+    #[test]
+    fn callback() {
+        let u = Matrix::<f32>::from([[4., 1.], [2., 5.]]);
+        let b = Vector::from([5., 7.]);
+        let mut log = vec![];
+        let options = StationaryOptions {
+            norm: Norm::Infinity,
+            ..Default::default()
+        };
+        let res = jacobi(&u, &b, &options, |k, x, r| log.push((k, x.clone(), r))).unwrap();
+        assert_eq!(log.len(), res.iterations());
+        assert_eq!(log[0].0, 1);
+        assert_eq!(log[0].1, Vector::from([1.25, 1.4]));
+        assert_approx_eq!(f32, log[0].2, 2.5, epsilon = 1e-6);
+        assert!(log
+            .iter()
+            .zip(&res.residuals()[1..])
+            .all(|((_, _, r), s)| r == s));
+    }
+
+    #[test]
+    fn positive_definite() {
+        let u = Matrix::from([[1., 0.8, 0.8], [0.8, 1., 0.8], [0.8, 0.8, 1.]]);
+        let b = Vector::from([2.6, 2.6, 2.6]);
+        let options = StationaryOptions {
+            tol: 1e-7,
+            ..Default::default()
+        };
+        let err = jacobi(&u, &b, &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Divergent));
+
+        let res = gauss_seidel(&u, &b, &options, |_, _, _| {}).unwrap();
+        assert!(res.converged());
+        assert_vector_approx_eq(res.solution(), &[1., 1., 1.]);
+    }
+
+    #[test]
+    fn over_relaxation() {
+        let n = 10;
+        let u = poisson(n);
+        let b: Vector<f32> = (0..n)
+            .map(|i| if i == 0 || i == n - 1 { 1. } else { 0. })
+            .collect();
+        let options = StationaryOptions {
+            max_iterations: 2000,
+            ..Default::default()
+        };
+        let omega = 2. / (1. + (std::f32::consts::PI / (n as f32 + 1.)).sin());
+        let optimal = sor(&u, &b, omega, &options, |_, _, _| {}).unwrap();
+        let res = gauss_seidel(&u, &b, &options, |_, _, _| {}).unwrap();
+        assert!(optimal.converged() && res.converged());
+        assert!(optimal.iterations() * 3 < res.iterations());
+        assert_vector_approx_eq(optimal.solution(), &[1.; 10]);
+
+        let err = sor(&u, &b, 2., &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Divergent));
+    }
+
+    #[test]
+    fn divergent() {
+        let u = Matrix::from([[1., 2.], [2., 1.]]);
+        let b = Vector::from([3., 3.]);
+        let options = StationaryOptions::default();
+        let err = jacobi(&u, &b, &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Divergent));
+        let err = gauss_seidel(&u, &b, &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Divergent));
+
+        let err = jacobi(
+            &Matrix::from([[0., 1.], [1., 0.]]),
+            &b,
+            &options,
+            |_, _, _| {},
+        )
+        .unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        for a in [[[1e-20, 1.], [1., 1.]], [[f32::NAN, 1.], [1., 1.]]] {
+            let err = gauss_seidel(&Matrix::from(a), &b, &options, |_, _, _| {}).unwrap_err();
+            assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        }
+        let a = Matrix::from([[4., -1.], [-1., 4.]]);
+        let err = sor(&a, &b, f32::NAN, &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Divergent));
+        let err = jacobi(&u, &Vector::from([1.]), &options, |_, _, _| {}).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<Error>(),
+            Some(&Error::DimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn iteration_limit() {
+        let u = poisson(10);
+        let b: Vector<f32> = vec![1.; 10].into();
+        let options = StationaryOptions {
+            max_iterations: 5,
+            ..Default::default()
+        };
+        let res = gauss_seidel(&u, &b, &options, |_, _, _| {}).unwrap();
+        assert!(!res.converged());
+        assert_eq!(res.iterations(), 5);
+    }
+}
@@ -0,0 +1,133 @@
+use matrix::{
+    matrix::Matrix,
+    strassen::{Algorithm, DEFAULT_CUTOFF},
+};
+
+fn main() {
+    let n = 300;
+    let data = (0..n * n).map(|i| ((i * 37) % 23) as f64 - 11.).collect();
+    let a = Matrix::from_vec(n, n, data).unwrap();
+    let classical = a.mul_mat_with(&a, Algorithm::Classical);
+    let strassen = a.mul_mat_with(
+        &a,
+        Algorithm::Strassen {
+            cutoff: DEFAULT_CUTOFF,
+        },
+    );
+    let error = (classical.as_slice().iter())
+        .zip(strassen.as_slice())
+        .fold(0f64, |max, (x, y)| max.max((x - y).abs()));
+    println!("{}", error);
+}
+
+#[cfg(test)]
+mod strassen {
+    use super::*;
+    use matrix::strassen::strassen;
+    use num::Complex;
+
+    /// Deterministic `m`x`n` matrix with entries in `[-1, 1]`.
+    fn sample(m: usize, n: usize, seed: usize) -> Matrix<f64> {
+        let data = (0..m * n)
+            .map(|i| ((i * 7919 + seed * 104729) % 2001) as f64 / 1000. - 1.)
+            .collect();
+        Matrix::from_vec(m, n, data).unwrap()
+    }
+
+    /// Largest difference between the Strassen and the classical product, relative to the
+    /// largest element of `|A| |B|`, which bounds the rounding errors of both.
+    fn relative_error(a: &Matrix<f64>, b: &Matrix<f64>, cutoff: usize) -> f64 {
+        let classical = a.as_view().mul_mat(b);
+        let fast = a.mul_mat_with(b, Algorithm::Strassen { cutoff });
+        let abs = |u: &Matrix<f64>| {
+            let data = u.as_slice().iter().map(|x| x.abs()).collect();
+            Matrix::from_vec(u.rows(), u.columns(), data).unwrap()
+        };
+        let scale = abs(a)
+            .as_view()
+            .mul_mat(&abs(b))
+            .as_slice()
+            .iter()
+            .fold(0f64, |max, x| max.max(*x));
+        (classical.as_slice().iter())
+            .zip(fast.as_slice())
+            .fold(0f64, |max, (x, y)| max.max((x - y).abs()))
+            / scale
+    }
+
+    #[test]
+    fn matches_classical_on_powers_of_two() {
+        for (n, cutoff) in [(2, 1), (16, 2), (64, 8), (256, 32)] {
+            let (a, b) = (sample(n, n, 1), sample(n, n, 2));
+            assert!(relative_error(&a, &b, cutoff) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn matches_classical_on_odd_and_rectangular_shapes() {
+        for (m, k, n, cutoff) in [
+            (3, 3, 3, 1),
+            (5, 7, 9, 1),
+            (33, 17, 65, 4),
+            (127, 129, 131, 16),
+            (203, 157, 181, 20),
+            (40, 300, 6, 2),
+        ] {
+            let (a, b) = (sample(m, k, 3), sample(k, n, 4));
+            let product = a.mul_mat_with(&b, Algorithm::Strassen { cutoff });
+            assert_eq!((product.rows(), product.columns()), (m, n));
+            assert!(relative_error(&a, &b, cutoff) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn exact_on_integers() {
+        let a = Matrix::from_vec(37, 41, (0..37 * 41).map(|i| (i % 13) as f64 - 6.).collect());
+        let b = Matrix::from_vec(41, 29, (0..41 * 29).map(|i| (i % 7) as f64 - 3.).collect());
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert_eq!(
+            strassen(a.as_view(), b.as_view(), 2),
+            a.as_view().mul_mat(&b)
+        );
+    }
+
+    #[test]
+    fn below_cutoff_and_empty() {
+        let (a, b) = (sample(10, 10, 5), sample(10, 10, 6));
+        let strassen = Algorithm::Strassen { cutoff: 10 };
+        assert_eq!(a.mul_mat_with(&b, strassen), a.as_view().mul_mat(&b));
+        assert_eq!(Algorithm::default(), Algorithm::Classical);
+
+        let empty = Matrix::<f64>::zeros(4, 0);
+        let product = empty.mul_mat_with(&Matrix::zeros(0, 3), Algorithm::Strassen { cutoff: 0 });
+        assert_eq!(product, Matrix::zeros(4, 3));
+    }
+
+    #[test]
+    fn views_single_and_complex() {
+        let (a, b) = (sample(70, 90, 7), sample(80, 95, 8));
+        let (u, v) = (a.view(1..61, ..), b.view(5.., 2..92).transpose());
+        let expected = u.mul_mat(v);
+        let fast = u.mul_mat_with(v, Algorithm::Strassen { cutoff: 8 });
+        (expected.as_slice().iter())
+            .zip(fast.as_slice())
+            .for_each(|(x, y)| assert!((x - y).abs() < 1e-10));
+
+        let data = (0..48 * 48).map(|i| (i % 9) as f32 - 4.).collect();
+        let a = Matrix::<f32>::from_vec(48, 48, data).unwrap();
+        let strassen = Algorithm::Strassen { cutoff: 4 };
+        assert_eq!(a.mul_mat_with(&a, strassen), a.as_view().mul_mat(&a));
+
+        let data = (0..20 * 20)
+            .map(|i| Complex::new((i % 5) as f64 - 2., (i % 3) as f64 - 1.))
+            .collect();
+        let a = Matrix::from_vec(20, 20, data).unwrap();
+        assert_eq!(a.mul_mat_with(&a, strassen), a.as_view().mul_mat(&a));
+    }
+
+    #[test]
+    #[should_panic(expected = "inner dimensions of the matrices must agree")]
+    fn inner_dimension_mismatch() {
+        strassen(sample(4, 5, 0).as_view(), sample(4, 5, 0).as_view(), 1);
+    }
+}
@@ -0,0 +1,179 @@
+use matrix::{
+    matrix::Matrix,
+    structured::{Diagonal, LowerTriangular, Symmetric, UpperTriangular},
+    vector::Vector,
+};
+
+fn main() {
+    let a = Matrix::from([[4., 2., 2.], [2., 5., 3.], [2., 3., 6.]]);
+    if let Ok(cholesky) = a.cholesky() {
+        if let Ok(l) = LowerTriangular::try_from(cholesky.l()) {
+            println!("{}", l.determinant());
+            let b = Vector::from([8., 10., 11.]);
+            if let Ok(y) = l.solve(&b) {
+                println!("{:?}", l.transpose().solve(&y).map(|x| x.to_string()));
+            }
+        }
+    }
+    if let Ok(covariance) = Symmetric::try_from(&a) {
+        println!("{:?}", covariance.packed());
+    }
+    let scale = Diagonal::new(vec![1., 2., 3.]);
+    println!("{}", scale.mul_mat(&a));
+    if let Ok(u) = UpperTriangular::try_from(&Matrix::from([[1., 2.], [0., 3.]])) {
+        println!("{}", u.as_matrix());
+    }
+}
+
+#[cfg(test)]
+mod structured {
+    use super::*;
+    use float_cmp::*;
+    use matrix::{error::Error, lu::Lu};
+
+    fn assert_vector_approx_eq(u: &Vector<f32>, v: &Vector<f32>) {
+        assert_eq!(u.len(), v.len());
+        u.iter()
+            .zip(v.iter())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    #[test]
+    fn upper_triangular() {
+        let dense = Matrix::from([[2., -1., 3.], [0., 4., 1.], [0., 0., -5.]]);
+        let u = UpperTriangular::try_from(&dense).unwrap();
+        assert_eq!(u.determinant(), -40.);
+        let x = Vector::from([1., 2., -1.]);
+        assert_eq!(u.mul_vec(&x), dense.mul_vec(&x));
+        assert_vector_approx_eq(&u.solve(&u.mul_vec(&x)).unwrap(), &x);
+        assert_eq!(u.mul_mat(&u).as_matrix(), &dense.mul_mat(&dense.clone()));
+        assert_eq!(Matrix::from(u.transpose()), dense.transpose());
+
+        let lu = Lu::new(&Matrix::<f32>::from([[1., 2.], [3., 4.]])).unwrap();
+        let u = UpperTriangular::try_from(lu.u()).unwrap();
+        assert_approx_eq!(f32, u.determinant().abs(), 2., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn lower_triangular() {
+        let dense = Matrix::from([[2., 0., 0.], [1., 3., 0.], [-1., 2., 4.]]);
+        let l = LowerTriangular::try_from(&dense).unwrap();
+        assert_eq!(l.determinant(), 24.);
+        let x = Vector::from([1., -2., 0.5]);
+        assert_eq!(l.mul_vec(&x), dense.mul_vec(&x));
+        assert_vector_approx_eq(&l.solve(&l.mul_vec(&x)).unwrap(), &x);
+        assert_eq!(l.mul_mat(&l).as_matrix(), &dense.mul_mat(&dense.clone()));
+        assert_eq!(l.transpose().as_matrix(), &dense.transpose());
+
+        let a = Matrix::from([[4., 2.], [2., 5.]]);
+        let cholesky = a.cholesky().unwrap();
+        let l = LowerTriangular::try_from(cholesky.l()).unwrap();
+        let b = Vector::from([6., 7.]);
+        let x = l.transpose().solve(&l.solve(&b).unwrap()).unwrap();
+        assert_vector_approx_eq(&x, &Vector::from([1., 1.]));
+    }
+
+    #[test]
+    fn triangular_errors() {
+        let u = Matrix::from([[1., 2.], [0.5, 1.]]);
+        let err = UpperTriangular::try_from(&u).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotTriangular));
+        assert!(UpperTriangular::with_tol(&u, 0.5).is_ok());
+        assert!(LowerTriangular::try_from(&u).is_err());
+        assert!(UpperTriangular::try_from(&Matrix::from([[1., 2.]])).is_err());
+
+        let singular = UpperTriangular::try_from(&Matrix::from([[1., 2.], [0., 0.]])).unwrap();
+        let err = singular.solve(&Vector::from([1., 1.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        let nearly_singular = Matrix::from([[1e-30, 1.], [0., 1.]]);
+        let u = UpperTriangular::try_from(&nearly_singular).unwrap();
+        let err = u.solve(&Vector::from([1., 1.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        let l = LowerTriangular::try_from(&nearly_singular.transpose()).unwrap();
+        assert!(l.solve(&Vector::from([1., 1.])).is_err());
+        let l = LowerTriangular::try_from(&Matrix::from([[1., 0.], [2., 1.]])).unwrap();
+        assert!(l.solve(&Vector::from([1., 1., 1.])).is_err());
+    }
+
+    #[test]
+    fn structured_nan() {
+        let nan = Matrix::from([[2., 1.], [f64::NAN, 3.]]);
+        let err = UpperTriangular::try_from(&nan).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotTriangular));
+        assert!(UpperTriangular::with_tol(&nan, 1.).is_err());
+        let err = LowerTriangular::try_from(&nan.transpose()).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotTriangular));
+        let err = Symmetric::try_from(&nan).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotSymmetric));
+        let err = Diagonal::try_from(&Matrix::from([[2., f64::NAN], [0., 1.]])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotDiagonal));
+
+        let u = UpperTriangular::try_from(&Matrix::from([[f64::NAN, 1.], [0., 1.]])).unwrap();
+        let err = u.solve(&Vector::from([1., 1.])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+    }
+
+    #[test]
+    fn symmetric() {
+        let dense = Matrix::from([[4., 1., -2.], [1., 3., 0.], [-2., 0., 5.]]);
+        let s = Symmetric::try_from(&dense).unwrap();
+        assert_eq!(s.packed(), [4., 1., 3., -2., 0., 5.]);
+        assert_eq!((s[(0, 2)], s[(2, 0)]), (-2., -2.));
+        assert_eq!(s.to_matrix(), dense);
+        let x = Vector::from([1., -1., 2.]);
+        assert_eq!(s.mul_vec(&x), dense.mul_vec(&x));
+        assert_approx_eq!(
+            f32,
+            s.determinant().unwrap(),
+            dense.determinant().unwrap(),
+            epsilon = 1e-4
+        );
+
+        let mut s = s;
+        s[(2, 1)] = 7.;
+        assert_eq!(s[(1, 2)], 7.);
+
+        let almost = Matrix::from([[1., 2.], [2.001, 1.]]);
+        let err = Symmetric::try_from(&almost).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotSymmetric));
+        let s = Symmetric::with_tol(&almost, 1e-2).unwrap();
+        assert_approx_eq!(f32, s[(0, 1)], 2.0005, epsilon = 1e-6);
+        assert_approx_eq!(f32, s.determinant().unwrap(), -3.002, epsilon = 1e-4);
+
+        let mut s = s;
+        s[(1, 0)] = f32::NAN;
+        let err = s.determinant().unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotSymmetric));
+    }
+
+    #[test]
+    fn diagonal() {
+        let d = Diagonal::try_from(&Matrix::from([[2., 0.], [0., -4.]])).unwrap();
+        assert_eq!(d.diag(), [2., -4.]);
+        assert_eq!(d.determinant(), -8.);
+        assert_eq!(d.mul_vec(&Vector::from([1., 1.])), Vector::from([2., -4.]));
+        assert_eq!(
+            d.solve(&Vector::from([1., 1.])).unwrap(),
+            Vector::from([0.5, -0.25])
+        );
+        assert_eq!(
+            Matrix::from(&d.inverse().unwrap()),
+            Matrix::from([[0.5, 0.], [0., -0.25]])
+        );
+
+        let err = Diagonal::try_from(&Matrix::from([[1., 1e-3], [0., 1.]])).unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::NotDiagonal));
+        assert!(Diagonal::with_tol(&Matrix::from([[1., 1e-3], [0., 1.]]), 1e-2).is_ok());
+        let err = Diagonal::new(vec![1., 0.]).inverse().unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        let err = Diagonal::new(vec![1., 1e-300])
+            .solve(&Vector::from([1., 1.]))
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+        assert!(Diagonal::new(vec![1., f64::NAN]).inverse().is_err());
+        let tiny = Diagonal::new(vec![2f64.powi(-900), 2f64.powi(-899)])
+            .inverse()
+            .unwrap();
+        assert_eq!(tiny.diag(), [2f64.powi(900), 2f64.powi(899)]);
+    }
+}
@@ -0,0 +1,80 @@
+use matrix::matrix::Matrix;
+
+fn main() {
+    let u = Matrix::from([[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]]);
+    if let Ok(eigen) = u.symmetric_eigen() {
+        println!("{}", eigen.eigenvalues());
+        println!("{}", eigen.eigenvectors());
+    }
+}
+
+#[cfg(test)]
+mod symmetric_eigen {
+    use super::*;
+    use float_cmp::*;
+
+    fn assert_matrix_approx_eq(u: &Matrix<f32>, v: &Matrix<f32>) {
+        assert_eq!((u.rows(), u.columns()), (v.rows(), v.columns()));
+        u.as_slice()
+            .iter()
+            .zip(v.as_slice())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-4));
+    }
+
+    fn assert_valid_eigen(a: &Matrix<f32>) {
+        let eigen = a.symmetric_eigen().unwrap();
+        let (values, vectors) = (eigen.eigenvalues(), eigen.eigenvectors());
+        assert!(values.windows(2).all(|w| w[0] <= w[1]));
+        assert_matrix_approx_eq(
+            &vectors.transpose().mul_mat(vectors),
+            &Matrix::identity(a.columns()),
+        );
+        let mut scaled = vectors.clone();
+        (0..a.columns()).for_each(|i| (0..a.columns()).for_each(|j| scaled[i][j] *= values[j]));
+        assert_matrix_approx_eq(&a.mul_mat(vectors), &scaled);
+    }
+
+    #[test]
+    fn matrix_symmetric_eigen() {
+        let eigen = Matrix::<f32>::from([[2., 1.], [1., 2.]])
+            .symmetric_eigen()
+            .unwrap();
+        assert_approx_eq!(f32, eigen.eigenvalues()[0], 1., epsilon = 1e-6);
+        assert_approx_eq!(f32, eigen.eigenvalues()[1], 3., epsilon = 1e-6);
+
+        let eigen = Matrix::<f32>::from([[2., 0., 0.], [0., 3., 4.], [0., 4., 9.]])
+            .symmetric_eigen()
+            .unwrap();
+        [1., 2., 11.]
+            .iter()
+            .zip(eigen.eigenvalues().iter())
+            .for_each(|(a, b)| assert_approx_eq!(f32, *a, *b, epsilon = 1e-5));
+
+        assert_valid_eigen(&Matrix::from([[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]]));
+        assert_valid_eigen(&Matrix::from([
+            [2., -1., 0., 0., 0., 0.],
+            [-1., 2., -1., 0., 0., 0.],
+            [0., -1., 2., -1., 0., 0.],
+            [0., 0., -1., 2., -1., 0.],
+            [0., 0., 0., -1., 2., -1.],
+            [0., 0., 0., 0., -1., 2.],
+        ]));
+        assert_valid_eigen(&Matrix::from([[1., 1.], [1., 1.]]));
+    }
+
+    #[test]
+    fn matrix_symmetric_eigen_errors() {
+        assert!(Matrix::from([[1., 2.], [3., 4.]])
+            .symmetric_eigen()
+            .is_err());
+
+        let u = Matrix::from([[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]]);
+        assert!(u.symmetric_eigen_with_tol(1e-3, 1).is_err());
+        assert!(u.symmetric_eigen_with_tol(1e-3, 10).is_ok());
+
+        let u = Matrix::from([[3., 0.], [0., 1.]]);
+        let eigen = u.symmetric_eigen_with_tol(0., 0).unwrap();
+        assert_eq!(eigen.eigenvalues()[0], 1.);
+        assert_eq!(eigen.eigenvectors(), &Matrix::from([[0., 1.], [1., 0.]]));
+    }
+}
@@ -20,4 +20,33 @@ mod transpose {
             Matrix::from([[1., 3., 6.], [2., 4., 7.], [3., 5., 8.]])
         );
     }
+
+    #[test]
+    fn matrix_transpose_rectangular() {
+        let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+        let t = u.transpose();
+        assert_eq!((t.rows(), t.columns()), (3, 2));
+        assert_eq!(t.as_slice(), &[1., 4., 2., 5., 3., 6.]);
+        assert_eq!(t.transpose(), u);
+    }
+
+    #[test]
+    fn matrix_storage() {
+        let mut u = Matrix::from_vec(2, 3, vec![1., 2., 3., 4., 5., 6.]).unwrap();
+        assert_eq!(u.strides(), (3, 1));
+        assert_eq!(u[(1, 2)], 6.);
+        assert_eq!(&u[1], &[4., 5., 6.]);
+        u[(0, 1)] = 7.;
+        u.swap_rows(0, 1);
+        assert_eq!(u, Matrix::from([[4., 5., 6.], [1., 7., 3.]]));
+        assert_eq!(u.row_iter().count(), 2);
+        assert!(Matrix::<f32>::from_vec(2, 3, vec![1.; 5]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix_ragged() {
+        use matrix::vector::Vector;
+        let _: Matrix<f32> = vec![Vector::from([1., 2.]), Vector::from([3.])].into();
+    }
 }
@@ -0,0 +1,172 @@
+use matrix::{lu::Lu, matrix::Matrix};
+
+fn main() {
+    let u = Matrix::from([[2., 1., 0., 5.], [4., 3., 0., 6.], [7., 8., 9., 1.]]);
+    println!("{}", u.view(..2, ..2).to_matrix());
+    println!("{}", u.diag().to_vector());
+    if let Ok(lu) = Lu::new(u.view(..2, ..2)) {
+        println!("{}", lu.determinant());
+    }
+}
+
+#[cfg(test)]
+mod views {
+    use super::*;
+    use matrix::{
+        cholesky::Cholesky,
+        svd::Svd,
+        vector::Vector,
+        view::{MatrixView, MatrixViewMut},
+    };
+
+    fn sample() -> Matrix<f32> {
+        Matrix::from([[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]])
+    }
+
+    #[test]
+    fn matrix_view() {
+        let u = sample();
+        let block = u.view(1.., 1..3);
+        assert_eq!((block.rows(), block.columns()), (2, 2));
+        assert_eq!(block.strides(), (4, 1));
+        assert_eq!(block[(1, 0)], 10.);
+        assert_eq!(block.get(2, 0), None);
+        assert_eq!(block.to_matrix(), Matrix::from([[6., 7.], [10., 11.]]));
+        assert_eq!(u.view(.., ..), u.as_view());
+        assert_eq!(u.view(1..1, ..).to_matrix().rows(), 0);
+    }
+
+    #[test]
+    fn matrix_view_row_col_diag() {
+        let u = sample();
+        assert_eq!(u.row(1).to_vector(), Vector::from([5., 6., 7., 8.]));
+        assert_eq!(u.col(2).to_vector(), Vector::from([3., 7., 11.]));
+        assert_eq!(u.diag().to_vector(), Vector::from([1., 6., 11.]));
+        assert_eq!(
+            u.view(.., 1..).diag().to_vector(),
+            Vector::from([2., 7., 12.])
+        );
+        assert_eq!(u.view(1.., ..).col(0).to_vector(), Vector::from([5., 9.]));
+    }
+
+    #[test]
+    fn matrix_view_strided() {
+        let u = sample();
+        let strided = u.as_view().strided(2, 3);
+        assert_eq!(strided.strides(), (8, 3));
+        assert_eq!(strided.to_matrix(), Matrix::from([[1., 4.], [9., 12.]]));
+        assert_eq!(u.as_view().transpose().to_matrix(), u.transpose());
+        assert_eq!(
+            u.view(..2, 1..).transpose().to_matrix(),
+            Matrix::from([[2., 6.], [3., 7.], [4., 8.]])
+        );
+    }
+
+    #[test]
+    fn matrix_view_products() {
+        let u = sample();
+        let x = Vector::from([1., -1.]);
+        assert_eq!(u.view(..2, 2..).mul_vec(&x), Vector::from([-1., -1.]));
+        assert_eq!(
+            u.view(..2, ..2).transpose().mul_vec(&x),
+            Vector::from([-4., -4.])
+        );
+
+        let v = Matrix::from([[1., 0.], [0., 1.], [1., 1.]]);
+        assert_eq!(
+            u.view(.., ..3).mul_mat(&v),
+            Matrix::from([[4., 5.], [12., 13.], [20., 21.]])
+        );
+        assert_eq!(
+            u.view(.., ..2).mul_mat(v.view(..2, ..)),
+            u.view(.., ..2).to_matrix()
+        );
+        assert_eq!(u.mul_mat(&Matrix::identity(4)), sample());
+        let gram = u.as_view().transpose().mul_mat(u.as_view());
+        assert_eq!(gram, u.transpose().mul_mat(&u));
+    }
+
+    #[test]
+    fn matrix_view_arithmetic() {
+        let u = sample();
+        let sum = u.view(..2, ..2) + u.view(1.., 2..);
+        assert_eq!(sum, Matrix::from([[8., 10.], [16., 18.]]));
+        let difference = u.view(1.., 2..) - &Matrix::identity(2);
+        assert_eq!(difference, Matrix::from([[6., 8.], [11., 11.]]));
+
+        let mut w = Matrix::identity(2);
+        w += u.view(..2, ..2);
+        w -= u.view(..2, ..2).transpose();
+        assert_eq!(w, Matrix::from([[1., -3.], [3., 1.]]));
+    }
+
+    #[test]
+    fn matrix_view_mut() {
+        let mut u = sample();
+        let mut block = u.view_mut(1.., 2..);
+        block += &Matrix::from([[1., 1.], [1., 1.]]);
+        block *= 2.;
+        block.col_mut(0).fill(0.);
+        assert_eq!(
+            block.as_view().to_matrix(),
+            Matrix::from([[0., 18.], [0., 26.]])
+        );
+
+        u.diag_mut().fill(-1.);
+        u.row_mut(0)
+            .copy_from(Matrix::from([[4., 3., 2., 1.]]).as_view());
+        let mut whole = MatrixViewMut::from(&mut u);
+        whole.view_mut(.., 3..).apply(|x| *x = -*x);
+        assert_eq!(
+            u,
+            Matrix::from([[4., 3., 2., -1.], [5., -1., 0., -18.], [9., 10., -1., -26.]])
+        );
+    }
+
+    #[test]
+    fn matrix_view_block_elimination() {
+        // Replaces the trailing block by the Schur complement of the leading one, in place.
+        let mut u = Matrix::from([
+            [4., 1., 1., 0.],
+            [1., 3., 0., 1.],
+            [1., 0., 2., 1.],
+            [0., 1., 1., 3.],
+        ]);
+        let lu = Lu::new(u.view(..2, ..2)).unwrap();
+        let update = u
+            .view(2.., ..2)
+            .mul_mat(&lu.inverse().unwrap())
+            .mul_mat(u.view(..2, 2..));
+        let mut a22 = u.view_mut(2.., 2..);
+        a22 -= &update;
+
+        let full = Matrix::from([
+            [4., 1., 1., 0.],
+            [1., 3., 0., 1.],
+            [1., 0., 2., 1.],
+            [0., 1., 1., 3.],
+        ]);
+        let expected: f32 = full.determinant().unwrap() / lu.determinant();
+        let schur = Lu::new(u.view(2.., 2..)).unwrap();
+        assert!((schur.determinant() - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn matrix_view_decompositions() {
+        let u = Matrix::from([[9., 4., 2., 1.], [4., 5., 1., 0.], [2., 1., 3., 7.]]);
+        let block: MatrixView<f32> = u.view(.., ..3);
+        let cholesky = Cholesky::new(block).unwrap();
+        assert_eq!(cholesky.l()[0][0], 3.);
+        let lu = Lu::new(block).unwrap();
+        assert!((lu.determinant() - block.to_matrix().determinant().unwrap()).abs() < 1e-3);
+        let svd = Svd::new(u.view(.., 1..).transpose());
+        assert_eq!(svd.singular_values().len(), 3);
+        assert!(Cholesky::new(u.view(.., 1..)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn matrix_view_out_of_bounds() {
+        sample().view(..4, ..);
+    }
+}
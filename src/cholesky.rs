@@ -0,0 +1,92 @@
+use crate::{error::Error, matrix::Matrix, parallel, vector::Vector, view::MatrixView, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast};
+
+/// Cholesky decomposition `A = LLᴴ` of a Hermitian positive-definite [`Matrix`], where `L` is
+/// lower triangular with a positive real diagonal. For real matrices `Lᴴ` is the transpose `Lᵀ`
+/// and Hermitian means symmetric.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cholesky<K> {
+    l: Matrix<K>,
+}
+
+impl<K: Scalar> Cholesky<K> {
+    /// Factorizes the given [`Matrix`]. Returns an error if it is not Hermitian positive-definite,
+    /// which includes positive semi-definite matrices whose pivots vanish up to rounding errors.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>) -> Result<Cholesky<K>>
+    where
+        K: 'a,
+    {
+        let a = a.into();
+        if !a.is_hermitian() {
+            return Err(Error::NotSymmetric.into());
+        }
+        let n = a.columns();
+        let mut l: Matrix<K> = Matrix::zeros(n, n);
+        let size = <K::Real as NumCast>::from(n).unwrap();
+
+        for j in 0..n {
+            let d = (0..j).fold(a[(j, j)].re(), |acc, k| acc - l[j][k].modulus_sqr());
+            if d <= size * K::Real::epsilon() * a[(j, j)].modulus() || d.is_nan() {
+                return Err(Error::NotPositiveDefinite.into());
+            }
+            l[j][j] = K::from_real(d.sqrt());
+            let (top, rest) = l.as_mut_slice().split_at_mut((j + 1) * n);
+            let lj = &top[j * n..];
+            parallel::for_each_chunk(rest, n, (n - j) * j, |r, li| {
+                let s = (0..j).fold(a[(j + 1 + r, j)], |acc, k| acc - li[k] * lj[k].conj());
+                li[j] = s / lj[j];
+            });
+        }
+        Ok(Cholesky { l })
+    }
+
+    /// Returns the lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    /// Solves `Ax = b` for `x` by forward substitution with `L` followed by back substitution with
+    /// `Lᴴ`.
+    /// ```
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let cholesky = Matrix::from([[4., 2.], [2., 5.]]).cholesky().unwrap();
+    /// assert_eq!(cholesky.solve(&Vector::from([6., 7.])).unwrap(), Vector::from([1., 1.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.l.n;
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        let mut x = b.clone();
+        for i in 0..n {
+            let s = (0..i).fold(x[i], |acc, j| acc - self.l[i][j] * x[j]);
+            x[i] = s / self.l[i][i];
+        }
+        for i in (0..n).rev() {
+            let s = ((i + 1)..n).fold(x[i], |acc, j| acc - self.l[j][i].conj() * x[j]);
+            x[i] = s / self.l[i][i];
+        }
+        Ok(x)
+    }
+
+    /// Solves `AX = B` for `X`, treating every column of `B` as a separate right-hand side.
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Matrix<K>> {
+        if b.m != self.l.n {
+            return Err(Error::DimensionMismatch {
+                expected: self.l.n,
+                found: b.m,
+            }
+            .into());
+        }
+        Ok(b.transpose()
+            .row_iter()
+            .map(|column| self.solve(&column.into()))
+            .collect::<Result<Matrix<K>>>()?
+            .transpose())
+    }
+}
@@ -0,0 +1,45 @@
+use derive_more::Display;
+
+/// Errors returned by the fallible operations of this crate. They are wrapped in an
+/// [`anyhow::Error`] and can be recovered with [`anyhow::Error::downcast_ref`].
+/// ```
+/// # use matrix::{error::Error, matrix::Matrix};
+/// let err = Matrix::from([[1., 2.], [2., 4.]]).inverse().unwrap_err();
+/// assert_eq!(err.downcast_ref::<Error>(), Some(&Error::Singular));
+/// ```
+#[derive(Clone, Copy, Debug, Display, PartialEq, Eq)]
+pub enum Error {
+    /// The length of an operand does not match the dimension it is combined with.
+    #[display(fmt = "Dimension mismatch: expected {}, found {}", expected, found)]
+    DimensionMismatch { expected: usize, found: usize },
+    /// The operation requires a square matrix.
+    #[display(fmt = "Matrix is not square: {}x{}", rows, columns)]
+    NotSquare { rows: usize, columns: usize },
+    /// The matrix is singular, or so close to singular that the result would be meaningless.
+    #[display(fmt = "Matrix is singular")]
+    Singular,
+    /// The operation requires a symmetric matrix.
+    #[display(fmt = "Matrix is not symmetric")]
+    NotSymmetric,
+    /// The operation requires a triangular matrix.
+    #[display(fmt = "Matrix is not triangular")]
+    NotTriangular,
+    /// The operation requires a diagonal matrix.
+    #[display(fmt = "Matrix is not diagonal")]
+    NotDiagonal,
+    /// The operation requires a Hermitian positive-definite matrix.
+    #[display(fmt = "Matrix is not positive definite")]
+    NotPositiveDefinite,
+    /// An iterative method did not converge within its iteration limit.
+    #[display(fmt = "Did not converge within {} iterations", iterations)]
+    NoConvergence { iterations: usize },
+    /// A stationary iteration would diverge because its iteration matrix has a spectral radius of
+    /// at least one.
+    #[display(fmt = "Iteration diverges: spectral radius is at least one")]
+    Divergent,
+    /// A sparse matrix does not have the sparsity pattern its symbolic analysis was computed for.
+    #[display(fmt = "Sparsity pattern does not match the symbolic analysis")]
+    PatternMismatch,
+}
+
+impl std::error::Error for Error {}
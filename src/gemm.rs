@@ -0,0 +1,163 @@
+use crate::{
+    parallel,
+    simd::{Isa, MR, NR},
+    view::{MatrixView, MatrixViewMut},
+    Scalar,
+};
+
+/// Depth of the blocks of `A` and `B` that are packed at once, which keeps a packed panel of `B`
+/// in the L1 cache.
+pub const KC: usize = 256;
+
+/// Number of rows of the packed blocks of `A`, which keeps a packed block in the L2 cache.
+pub const MC: usize = 64;
+
+/// Number of columns of the packed blocks of `B`, which keeps a packed block in the L3 cache.
+pub const NC: usize = 1024;
+
+/// Computes `C = alpha AB + beta C` in place, where `A`, `B` and `C` may be matrices or views.
+/// Blocks of `A` and `B` are packed into panels of [`MR`] rows and [`NR`] columns, which the
+/// [`Kernels::gemm_kernel`](crate::simd::Kernels::gemm_kernel) multiplies while they stay in the
+/// cache. The only allocations are the packing buffers. With the `parallel` feature, blocks of
+/// rows of `C` are updated by separate threads, each of which packs its own blocks of `A`. As in
+/// BLAS, `C` is not read if `beta` is zero, so it may hold anything, including NaN. Panics if the
+/// shapes do not agree.
+/// ```
+/// # use matrix::{gemm::gemm, matrix::Matrix};
+/// let a = Matrix::from([[1., 2.], [3., 4.]]);
+/// let mut c = Matrix::identity(2);
+/// gemm(2., &a, &a, -1., &mut c);
+/// assert_eq!(c, Matrix::from([[13., 20.], [30., 43.]]));
+/// ```
+pub fn gemm<'a, 'b, 'c, K: Scalar>(
+    alpha: K,
+    a: impl Into<MatrixView<'a, K>>,
+    b: impl Into<MatrixView<'b, K>>,
+    beta: K,
+    c: impl Into<MatrixViewMut<'c, K>>,
+) {
+    let (a, b, mut c) = (a.into(), b.into(), c.into());
+    let (m, k, n) = (a.rows(), a.columns(), b.columns());
+    assert_eq!(k, b.rows(), "inner dimensions of the matrices must agree");
+    assert_eq!(
+        (c.rows(), c.columns()),
+        (m, n),
+        "shape of the output must match the product"
+    );
+
+    if beta == K::zero() {
+        c.fill(K::zero());
+    } else if beta != K::one() {
+        c.apply(|x| *x *= beta);
+    }
+    if m == 0 || n == 0 || k == 0 || alpha == K::zero() {
+        return;
+    }
+
+    let isa = Isa::detect();
+    let a_len = MC.min(m).next_multiple_of(MR) * KC.min(k);
+    let mut b_pack = vec![K::zero(); NC.min(n).next_multiple_of(NR) * KC.min(k)];
+    let (rs, cs) = c.strides();
+    let data = c.data_mut();
+    // Blocks of MC rows of C can only be updated by separate tasks if rows do not interleave.
+    let (rows, chunk) = if (n - 1) * cs < rs {
+        (MC, MC * rs)
+    } else {
+        (m, data.len())
+    };
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&b, pc, kc, jc, nc, &mut b_pack);
+            let b_pack = &b_pack;
+            parallel::for_each_chunk_init(
+                data,
+                chunk,
+                m * nc * kc,
+                || vec![K::zero(); a_len],
+                |a_pack, block, data| {
+                    let first = block * rows;
+                    for ic in (first..m.min(first + rows)).step_by(MC) {
+                        let mc = MC.min(m - ic);
+                        pack_a(alpha, &a, ic, mc, pc, kc, a_pack);
+                        for jr in (0..nc).step_by(NR) {
+                            let nr = NR.min(nc - jr);
+                            let b_panel = &b_pack[jr * kc..(jr + NR) * kc];
+                            for ir in (0..mc).step_by(MR) {
+                                let mr = MR.min(mc - ir);
+                                let a_panel = &a_pack[ir * kc..(ir + MR) * kc];
+                                let start = (ic - first + ir) * rs + (jc + jr) * cs;
+                                if mr == MR && nr == NR && cs == 1 && rs >= NR {
+                                    K::gemm_kernel(
+                                        isa,
+                                        kc,
+                                        a_panel,
+                                        b_panel,
+                                        &mut data[start..],
+                                        rs,
+                                    );
+                                } else {
+                                    let mut block = [K::zero(); MR * NR];
+                                    K::gemm_kernel(isa, kc, a_panel, b_panel, &mut block, NR);
+                                    for i in 0..mr {
+                                        for j in 0..nr {
+                                            data[start + i * rs + j * cs] += block[i * NR + j];
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Packs `alpha` times the `mc`x`kc` block of `A` at `(ic, pc)` into panels of [`MR`] rows, each
+/// of which stores its columns one after the other. Rows past the end of `A` are zero.
+fn pack_a<K: Scalar>(
+    alpha: K,
+    a: &MatrixView<'_, K>,
+    ic: usize,
+    mc: usize,
+    pc: usize,
+    kc: usize,
+    buf: &mut [K],
+) {
+    for (ir, panel) in (0..mc).step_by(MR).zip(buf.chunks_exact_mut(MR * kc)) {
+        for p in 0..kc {
+            for i in 0..MR {
+                panel[p * MR + i] = if ir + i < mc {
+                    alpha * a[(ic + ir + i, pc + p)]
+                } else {
+                    K::zero()
+                };
+            }
+        }
+    }
+}
+
+/// Packs the `kc`x`nc` block of `B` at `(pc, jc)` into panels of [`NR`] columns, each of which
+/// stores its rows one after the other. Columns past the end of `B` are zero.
+fn pack_b<K: Scalar>(
+    b: &MatrixView<'_, K>,
+    pc: usize,
+    kc: usize,
+    jc: usize,
+    nc: usize,
+    buf: &mut [K],
+) {
+    for (jr, panel) in (0..nc).step_by(NR).zip(buf.chunks_exact_mut(NR * kc)) {
+        let nr = NR.min(nc - jr);
+        for p in 0..kc {
+            let dst = &mut panel[p * NR..(p + 1) * NR];
+            match b.row_slice(pc + p) {
+                Some(row) => dst[..nr].copy_from_slice(&row[jc + jr..jc + jr + nr]),
+                None => (0..nr).for_each(|j| dst[j] = b[(pc + p, jc + jr + j)]),
+            }
+            dst[nr..].fill(K::zero());
+        }
+    }
+}
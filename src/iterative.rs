@@ -0,0 +1,412 @@
+use crate::{error::Error, matrix::Matrix, simd::Isa, vector::Vector, RealScalar, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+
+/// A linear map that can be applied to a [`Vector`] without being stored as a dense [`Matrix`].
+/// This is all the Krylov solvers in this module need to know about `A`.
+pub trait LinearOperator<K> {
+    /// Number of rows of the operator, i.e. the length of its output.
+    fn rows(&self) -> usize;
+
+    /// Number of columns of the operator, i.e. the length of its input.
+    fn columns(&self) -> usize;
+
+    /// Computes `Ax`.
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+}
+
+impl<K: Scalar> LinearOperator<K> for Matrix<K> {
+    fn rows(&self) -> usize {
+        self.m
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+/// An approximation `M⁻¹` of the inverse of an operator, used to speed up the Krylov solvers.
+pub trait Preconditioner<K> {
+    /// Computes `M⁻¹r`.
+    fn apply(&self, r: &Vector<K>) -> Vector<K>;
+}
+
+/// The identity preconditioner, which leaves the system unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Identity;
+
+impl<K: Clone> Preconditioner<K> for Identity {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        r.clone()
+    }
+}
+
+/// The Jacobi preconditioner, which scales by the inverse of the diagonal of a [`Matrix`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Jacobi<K> {
+    inverse_diagonal: Vector<K>,
+}
+
+impl<K: Scalar> Jacobi<K> {
+    /// Creates the preconditioner for the given square [`Matrix`]. Returns an error if a diagonal
+    /// element is NaN or, as in [`Lu`](crate::lu::Lu), not above `n * ε` times the largest entry of
+    /// its row.
+    pub fn new(a: &Matrix<K>) -> Result<Jacobi<K>> {
+        if a.m != a.n {
+            return Err(Error::NotSquare {
+                rows: a.m,
+                columns: a.n,
+            }
+            .into());
+        }
+        let size = <K::Real as NumCast>::from(a.n).unwrap();
+        if a.row_iter().enumerate().any(|(i, row)| {
+            let scale = row
+                .iter()
+                .fold(K::Real::zero(), |max, x| max.max(x.modulus()));
+            let pivot = row[i].modulus();
+            pivot <= size * K::Real::epsilon() * scale || pivot.is_nan()
+        }) {
+            return Err(Error::Singular.into());
+        }
+        Ok(Jacobi {
+            inverse_diagonal: (0..a.n).map(|i| K::one() / a[i][i]).collect(),
+        })
+    }
+}
+
+impl<K: Scalar> Preconditioner<K> for Jacobi<K> {
+    fn apply(&self, r: &Vector<K>) -> Vector<K> {
+        r.iter()
+            .zip(self.inverse_diagonal.iter())
+            .map(|(r, d)| *r * *d)
+            .collect()
+    }
+}
+
+/// Stopping criteria of the iterative solvers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IterativeOptions<K: Scalar> {
+    /// The iteration stops once `‖b - Ax‖ <= tol * ‖b‖`.
+    pub tol: K::Real,
+    /// Maximum number of iterations, counted as applications of the operator for GMRES.
+    pub max_iterations: usize,
+    /// Number of iterations after which GMRES restarts.
+    pub restart: usize,
+}
+
+impl<K: Scalar> Default for IterativeOptions<K> {
+    fn default() -> Self {
+        IterativeOptions {
+            tol: <K::Real as NumCast>::from(1e-5).unwrap(),
+            max_iterations: 1000,
+            restart: 30,
+        }
+    }
+}
+
+/// Result of an iterative solver together with a report on its convergence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution<K: Scalar> {
+    solution: Vector<K>,
+    residuals: Vec<K::Real>,
+    converged: bool,
+}
+
+impl<K: Scalar> IterativeSolution<K> {
+    pub(crate) fn new(solution: Vector<K>, residuals: Vec<K::Real>, converged: bool) -> Self {
+        IterativeSolution {
+            solution,
+            residuals,
+            converged,
+        }
+    }
+
+    /// Returns the last iterate, which is the solution if [`Self::converged`] holds.
+    pub fn solution(&self) -> &Vector<K> {
+        &self.solution
+    }
+
+    /// Returns the number of iterations that were performed.
+    pub fn iterations(&self) -> usize {
+        self.residuals.len() - 1
+    }
+
+    /// Returns the residual norm `‖b - Ax‖` of the initial guess followed by that of every
+    /// iteration. GMRES reports the residual norms estimated by its least-squares problem.
+    pub fn residuals(&self) -> &[K::Real] {
+        &self.residuals
+    }
+
+    /// Returns whether the tolerance was reached within the maximum number of iterations.
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+/// Solves `Ax = b` for a Hermitian positive-definite operator with the preconditioned conjugate
+/// gradient method. Returns an error if `A` turns out not to be positive definite.
+/// ```
+/// # use matrix::{iterative::{conjugate_gradient, Identity}, matrix::Matrix, vector::Vector};
+/// let a = Matrix::from([[4., 1.], [1., 3.]]);
+/// let res = conjugate_gradient(&a, &Vector::from([1., 2.]), &Identity, &Default::default()).unwrap();
+/// assert!(res.converged());
+/// assert_eq!(res.iterations(), 2);
+/// ```
+pub fn conjugate_gradient<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: &P,
+    options: &IterativeOptions<K>,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    A: LinearOperator<K>,
+    P: Preconditioner<K>,
+{
+    check_shape(a, b)?;
+    let threshold = options.tol * norm(b);
+    let mut x = zeros(b.len());
+    let mut r = b.clone();
+    let mut residuals = vec![norm(&r)];
+    if residuals[0] <= threshold {
+        return Ok(IterativeSolution::new(x, residuals, true));
+    }
+    let mut z = preconditioner.apply(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    for _ in 0..options.max_iterations {
+        let ap = a.apply(&p);
+        let pap = dot(&p, &ap);
+        if pap.re() <= K::Real::zero() {
+            return Err(Error::NotPositiveDefinite.into());
+        }
+        let alpha = rz / pap;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        residuals.push(norm(&r));
+        if residuals[residuals.len() - 1] <= threshold {
+            return Ok(IterativeSolution::new(x, residuals, true));
+        }
+        z = preconditioner.apply(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        p.iter_mut()
+            .zip(z.iter())
+            .for_each(|(p, z)| *p = *z + beta * *p);
+    }
+    Ok(IterativeSolution::new(x, residuals, false))
+}
+
+/// Solves `Ax = b` for a general square operator with the restarted GMRES method, using right
+/// preconditioning so that the reported residuals are those of the original system.
+/// ```
+/// # use matrix::{iterative::{gmres, Identity}, matrix::Matrix, vector::Vector};
+/// let a = Matrix::<f32>::from([[1., 2.], [-3., 1.]]);
+/// let res = gmres(&a, &Vector::from([3., -2.]), &Identity, &Default::default()).unwrap();
+/// assert!(res.converged());
+/// assert!((res.solution()[0] - 1.).abs() < 1e-5);
+/// ```
+pub fn gmres<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: &P,
+    options: &IterativeOptions<K>,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    A: LinearOperator<K>,
+    P: Preconditioner<K>,
+{
+    check_shape(a, b)?;
+    let n = b.len();
+    let restart = options.restart.clamp(1, n.max(1));
+    let threshold = options.tol * norm(b);
+    let mut x = zeros(n);
+    let mut residuals = vec![norm(b)];
+    let mut iterations = 0;
+
+    while residuals[residuals.len() - 1] > threshold && iterations < options.max_iterations {
+        let mut r = a.apply(&x);
+        r.iter_mut().zip(b.iter()).for_each(|(r, b)| *r = *b - *r);
+        let beta = norm(&r);
+
+        let mut basis = vec![r
+            .iter()
+            .map(|r| *r / K::from_real(beta))
+            .collect::<Vector<K>>()];
+        let mut directions = Vec::with_capacity(restart);
+        let mut h = vec![vec![K::zero(); restart]; restart + 1];
+        let (mut cs, mut sn) = (vec![K::zero(); restart], vec![K::zero(); restart]);
+        let mut g = vec![K::zero(); restart + 1];
+        g[0] = K::from_real(beta);
+
+        let mut k = 0;
+        while k < restart && iterations < options.max_iterations {
+            let z = preconditioner.apply(&basis[k]);
+            let mut w = a.apply(&z);
+            directions.push(z);
+            for (i, v) in basis.iter().enumerate() {
+                h[i][k] = dot(v, &w);
+                axpy(-h[i][k], v, &mut w);
+            }
+            h[k + 1][k] = K::from_real(norm(&w));
+
+            for i in 0..k {
+                let (x, y) = (h[i][k], h[i + 1][k]);
+                h[i][k] = cs[i].conj() * x + sn[i].conj() * y;
+                h[i + 1][k] = cs[i] * y - sn[i] * x;
+            }
+            let (x, y) = (h[k][k], h[k + 1][k]);
+            let r = x.modulus().hypot(y.modulus());
+            (cs[k], sn[k]) = if r == K::Real::zero() {
+                (K::one(), K::zero())
+            } else {
+                (x / K::from_real(r), y / K::from_real(r))
+            };
+            h[k][k] = K::from_real(r);
+            h[k + 1][k] = K::zero();
+            g[k + 1] = -sn[k] * g[k];
+            g[k] = cs[k].conj() * g[k];
+
+            iterations += 1;
+            k += 1;
+            residuals.push(g[k].modulus());
+            let breakdown = y == K::zero();
+            if residuals[residuals.len() - 1] <= threshold || breakdown {
+                break;
+            }
+            basis.push(w.iter().map(|w| *w / y).collect());
+        }
+
+        let mut y = g;
+        for i in (0..k).rev() {
+            let s = ((i + 1)..k).fold(y[i], |acc, j| acc - h[i][j] * y[j]);
+            y[i] = s / h[i][i];
+        }
+        (0..k).for_each(|i| axpy(y[i], &directions[i], &mut x));
+        if h[k - 1][k - 1] == K::zero() {
+            break;
+        }
+    }
+    let converged = residuals[residuals.len() - 1] <= threshold;
+    Ok(IterativeSolution::new(x, residuals, converged))
+}
+
+/// Solves `Ax = b` for a general square operator with the right-preconditioned BiCGSTAB method.
+/// The iteration stops without converging if the method breaks down.
+/// ```
+/// # use matrix::{iterative::{bicgstab, Identity}, matrix::Matrix, vector::Vector};
+/// let a = Matrix::<f32>::from([[1., 2.], [-3., 1.]]);
+/// let res = bicgstab(&a, &Vector::from([3., -2.]), &Identity, &Default::default()).unwrap();
+/// assert!(res.converged());
+/// assert!((res.solution()[1] - 1.).abs() < 1e-5);
+/// ```
+pub fn bicgstab<K, A, P>(
+    a: &A,
+    b: &Vector<K>,
+    preconditioner: &P,
+    options: &IterativeOptions<K>,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    A: LinearOperator<K>,
+    P: Preconditioner<K>,
+{
+    check_shape(a, b)?;
+    let n = b.len();
+    let threshold = options.tol * norm(b);
+    let mut x = zeros(n);
+    let mut r = b.clone();
+    let r_hat = r.clone();
+    let mut residuals = vec![norm(&r)];
+    let (mut rho, mut alpha, mut omega) = (K::one(), K::one(), K::one());
+    let (mut v, mut p) = (zeros(n), zeros(n));
+
+    for _ in 0..options.max_iterations {
+        if residuals[residuals.len() - 1] <= threshold {
+            break;
+        }
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == K::zero() || omega == K::zero() {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        p.iter_mut()
+            .zip(r.iter().zip(v.iter()))
+            .for_each(|(p, (r, v))| *p = *r + beta * (*p - omega * *v));
+
+        let p_hat = preconditioner.apply(&p);
+        v = a.apply(&p_hat);
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == K::zero() {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        axpy(alpha, &p_hat, &mut x);
+        let mut s = r.clone();
+        axpy(-alpha, &v, &mut s);
+        if norm(&s) <= threshold {
+            residuals.push(norm(&s));
+            break;
+        }
+
+        let s_hat = preconditioner.apply(&s);
+        let t = a.apply(&s_hat);
+        let tt = dot(&t, &t);
+        omega = if tt == K::zero() {
+            K::zero()
+        } else {
+            dot(&t, &s) / tt
+        };
+        axpy(omega, &s_hat, &mut x);
+        r = s;
+        axpy(-omega, &t, &mut r);
+        residuals.push(norm(&r));
+    }
+    let converged = residuals[residuals.len() - 1] <= threshold;
+    Ok(IterativeSolution::new(x, residuals, converged))
+}
+
+fn check_shape<K, A: LinearOperator<K>>(a: &A, b: &Vector<K>) -> Result<()> {
+    if a.rows() != a.columns() {
+        return Err(Error::NotSquare {
+            rows: a.rows(),
+            columns: a.columns(),
+        }
+        .into());
+    }
+    if b.len() != a.rows() {
+        return Err(Error::DimensionMismatch {
+            expected: a.rows(),
+            found: b.len(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn zeros<K: Scalar>(n: usize) -> Vector<K> {
+    vec![K::zero(); n].into()
+}
+
+/// Computes the Hermitian inner product `uᴴv`.
+fn dot<K: Scalar>(u: &[K], v: &[K]) -> K {
+    K::dotc(Isa::detect(), u, v)
+}
+
+fn norm<K: Scalar>(u: &[K]) -> K::Real {
+    u.iter().map(|u| u.modulus_sqr()).sum::<K::Real>().sqrt()
+}
+
+/// Computes `y += alpha * x`.
+fn axpy<K: Scalar>(alpha: K, x: &[K], y: &mut [K]) {
+    K::axpy(Isa::detect(), alpha, x, y);
+}
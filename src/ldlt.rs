@@ -0,0 +1,244 @@
+use crate::{error::Error, matrix::Matrix, parallel, vector::Vector, view::MatrixView, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+
+/// LDLᴴ decomposition `PᵀAP = LDLᴴ` of a Hermitian, possibly indefinite, [`Matrix`] computed with
+/// Bunch–Kaufman pivoting. `L` is unit lower triangular and `D` is Hermitian block diagonal with
+/// 1x1 and 2x2 blocks. For real matrices this is the LDLᵀ decomposition of a symmetric [`Matrix`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ldlt<K> {
+    l: Matrix<K>,
+    d: Matrix<K>,
+    blocks: Vec<usize>,
+    permutation: Vec<usize>,
+    singular: bool,
+}
+
+impl<K: Scalar> Ldlt<K> {
+    /// Factorizes the given [`Matrix`]. Returns an error if it is not Hermitian. A singular
+    /// [`Matrix`] can be factorized, but [`Ldlt::solve`] will return an error for it. As in
+    /// [`Lu`](crate::lu::Lu), a 1x1 pivot counts as zero when it is not above `n * ε` times the
+    /// largest entry of its original row, and a 2x2 block when its determinant is not above that
+    /// tolerance times its largest entry.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>) -> Result<Ldlt<K>>
+    where
+        K: 'a,
+    {
+        let mut a = a.into().to_matrix();
+        if !a.is_hermitian() {
+            return Err(Error::NotSymmetric.into());
+        }
+        let n = a.n;
+        let alpha = <K::Real as NumCast>::from((1. + 17f64.sqrt()) / 8.).unwrap();
+        let mut l = Matrix::identity(n);
+        let mut d = Matrix::zeros(n, n);
+        let mut blocks = Vec::new();
+        let mut permutation = (0..n).collect::<Vec<_>>();
+        let mut singular = false;
+        let tolerances: Vec<K::Real> = a
+            .row_iter()
+            .map(|row| {
+                row.iter()
+                    .fold(K::Real::zero(), |max, x| max.max(x.modulus()))
+                    * K::Real::epsilon()
+                    * <K::Real as NumCast>::from(n).unwrap()
+            })
+            .collect();
+
+        let mut k = 0;
+        while k < n {
+            let abs_kk = a[k][k].modulus();
+            let (r, col_max) = ((k + 1)..n).fold((k, K::Real::zero()), |(r, max), i| {
+                if a[i][k].modulus() > max {
+                    (i, a[i][k].modulus())
+                } else {
+                    (r, max)
+                }
+            });
+            let (pivot, size) = if abs_kk >= alpha * col_max || col_max == K::Real::zero() {
+                (k, 1)
+            } else {
+                let row_max = (k..n)
+                    .filter(|&j| j != r)
+                    .fold(K::Real::zero(), |max, j| max.max(a[r][j].modulus()));
+                if abs_kk * row_max >= alpha * col_max * col_max {
+                    (k, 1)
+                } else if a[r][r].modulus() >= alpha * row_max {
+                    (r, 1)
+                } else {
+                    (r, 2)
+                }
+            };
+
+            let target = k + size - 1;
+            if pivot != target {
+                a.swap_rows(pivot, target);
+                a.row_iter_mut().for_each(|row| row.swap(pivot, target));
+                permutation.swap(pivot, target);
+                for j in 0..k {
+                    let tmp = l[pivot][j];
+                    l[pivot][j] = l[target][j];
+                    l[target][j] = tmp;
+                }
+            }
+
+            if size == 1 {
+                let pivot = K::from_real(a[k][k].re());
+                d[k][k] = pivot;
+                let modulus = pivot.modulus();
+                if modulus <= tolerances[permutation[k]] || modulus.is_nan() {
+                    singular = true;
+                } else {
+                    for i in (k + 1)..n {
+                        l[i][k] = a[i][k] / pivot;
+                    }
+                    let ratios: Vec<K> = ((k + 1)..n).map(|i| l[i][k]).collect();
+                    let col: Vec<K> = ((k + 1)..n).map(|j| a[j][k].conj()).collect();
+                    let rest = &mut a.as_mut_slice()[(k + 1) * n..];
+                    parallel::for_each_chunk(rest, n, (n - k) * (n - k), |r, row| {
+                        for j in (k + 1)..n {
+                            let tmp = ratios[r] * col[j - k - 1];
+                            row[j] -= tmp;
+                        }
+                    });
+                }
+            } else {
+                let (d11, d21, d22) = (
+                    K::from_real(a[k][k].re()),
+                    a[k + 1][k],
+                    K::from_real(a[k + 1][k + 1].re()),
+                );
+                let det = d11 * d22 - d21 * d21.conj();
+                (d[k][k], d[k + 1][k], d[k][k + 1], d[k + 1][k + 1]) = (d11, d21, d21.conj(), d22);
+                let tol = tolerances[permutation[k]].max(tolerances[permutation[k + 1]]);
+                let max = d11.modulus().max(d21.modulus()).max(d22.modulus());
+                if det.modulus() <= tol * max || det.modulus().is_nan() {
+                    singular = true;
+                }
+                for i in (k + 2)..n {
+                    let (x, y) = (a[i][k], a[i][k + 1]);
+                    l[i][k] = (x * d22 - y * d21) / det;
+                    l[i][k + 1] = (y * d11 - x * d21.conj()) / det;
+                }
+                let ratios: Vec<(K, K)> = ((k + 2)..n).map(|i| (l[i][k], l[i][k + 1])).collect();
+                let cols: Vec<(K, K)> = ((k + 2)..n)
+                    .map(|j| (a[j][k].conj(), a[j][k + 1].conj()))
+                    .collect();
+                let rest = &mut a.as_mut_slice()[(k + 2) * n..];
+                parallel::for_each_chunk(rest, n, 2 * (n - k) * (n - k), |r, row| {
+                    let (l1, l2) = ratios[r];
+                    for j in (k + 2)..n {
+                        let (a1, a2) = cols[j - k - 2];
+                        let tmp = l1 * a1 + l2 * a2;
+                        row[j] -= tmp;
+                    }
+                });
+            }
+            blocks.push(size);
+            k += size;
+        }
+        Ok(Ldlt {
+            l,
+            d,
+            blocks,
+            permutation,
+            singular,
+        })
+    }
+
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    /// Returns the Hermitian block diagonal factor `D`.
+    pub fn d(&self) -> &Matrix<K> {
+        &self.d
+    }
+
+    /// Returns the symmetric permutation `P`: row and column `i` of `PᵀAP` are row and column
+    /// `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Checks whether the factorized [`Matrix`] is singular or numerically close to singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Calculates the determinant as the product of the determinants of the blocks of `D`, since
+    /// `L` has a unit diagonal and the permutation is applied on both sides.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let ldlt = Matrix::from([[0., 2.], [2., 3.]]).ldlt().unwrap();
+    /// assert_eq!(ldlt.determinant(), -4.);
+    /// ```
+    pub fn determinant(&self) -> K {
+        let mut k = 0;
+        self.blocks.iter().fold(K::one(), |acc, &size| {
+            let det = if size == 1 {
+                self.d[k][k]
+            } else {
+                self.d[k][k] * self.d[k + 1][k + 1] - self.d[k + 1][k] * self.d[k][k + 1]
+            };
+            k += size;
+            acc * det
+        })
+    }
+
+    /// Solves `Ax = b` for `x` using the factorization.
+    /// ```
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let ldlt = Matrix::from([[0., 1.], [1., 0.]]).ldlt().unwrap();
+    /// assert_eq!(ldlt.solve(&Vector::from([2., 3.])).unwrap(), Vector::from([3., 2.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.l.n;
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        if self.singular {
+            return Err(Error::Singular.into());
+        }
+        let mut y: Vector<K> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            let s = (0..i).fold(y[i], |acc, j| acc - self.l[i][j] * y[j]);
+            y[i] = s;
+        }
+
+        let mut k = 0;
+        for &size in &self.blocks {
+            if size == 1 {
+                y[k] /= self.d[k][k];
+            } else {
+                let (d11, d21, d12, d22) = (
+                    self.d[k][k],
+                    self.d[k + 1][k],
+                    self.d[k][k + 1],
+                    self.d[k + 1][k + 1],
+                );
+                let det = d11 * d22 - d21 * d12;
+                let (x, z) = (y[k], y[k + 1]);
+                y[k] = (x * d22 - z * d12) / det;
+                y[k + 1] = (z * d11 - x * d21) / det;
+            }
+            k += size;
+        }
+
+        for i in (0..n).rev() {
+            let s = ((i + 1)..n).fold(y[i], |acc, j| acc - self.l[j][i].conj() * y[j]);
+            y[i] = s;
+        }
+        let mut x = y.clone();
+        self.permutation
+            .iter()
+            .enumerate()
+            .for_each(|(i, &p)| x[p] = y[i]);
+        Ok(x)
+    }
+}
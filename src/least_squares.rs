@@ -0,0 +1,113 @@
+use crate::{
+    error::Error, matrix::Matrix, qr::householder_r, svd::Svd, vector::Vector, view::MatrixView,
+    Scalar,
+};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+
+/// Solution of the linear least-squares problem `min ‖Ax - b‖` for a [`Matrix`] `A` of shape
+/// `m`x`n`. If `A` is rank-deficient, the solution with the smallest norm is returned.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeastSquares<K: Scalar> {
+    solution: Vector<K>,
+    residual: K::Real,
+    rank: usize,
+    singular_values: Vector<K::Real>,
+}
+
+impl<K: Scalar> LeastSquares<K> {
+    /// Solves the least-squares problem. For `m >= n` the [`Matrix`] is reduced to `R` with the
+    /// Householder reflections of a [`Qr`](crate::qr::Qr) decomposition, which are applied to `b`
+    /// instead of forming `Q`. The singular values of `R` decide whether `R` is solved by back
+    /// substitution or, if it is rank-deficient, through its [`Svd`].
+    /// Underdetermined systems are solved through the [`Svd`] of `A` directly. Singular values not
+    /// larger than `max(m, n) * ε * σ_max` are treated as zero. Returns [`Error::Singular`] if `A`
+    /// holds a NaN, since no singular value can then be trusted.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>, b: &Vector<K>) -> Result<LeastSquares<K>>
+    where
+        K: 'a,
+    {
+        let a = a.into();
+        let (m, n) = (a.rows(), a.columns());
+        if b.len() != m {
+            return Err(Error::DimensionMismatch {
+                expected: m,
+                found: b.len(),
+            }
+            .into());
+        }
+        let size = <K::Real as NumCast>::from(m.max(n)).unwrap();
+
+        let (solution, rank, singular_values) = if m >= n {
+            let mut qtb = b.clone();
+            let r = householder_r(a, &mut qtb);
+            qtb.truncate(n);
+            let r = Matrix::from_vec(n, n, r.as_slice()[..n * n].to_vec())?;
+            let svd = r.svd();
+            let tol = size * K::Real::epsilon() * max_singular_value(&svd);
+            let rank = svd.rank(tol);
+
+            let solution = if rank == n {
+                let mut x = qtb;
+                for i in (0..n).rev() {
+                    let s = ((i + 1)..n).fold(x[i], |acc, j| acc - r[i][j] * x[j]);
+                    x[i] = s / r[i][i];
+                }
+                x
+            } else {
+                svd.pinv(tol).mul_vec(&qtb)
+            };
+            (solution, rank, svd.singular_values().clone())
+        } else {
+            let svd = Svd::new(a);
+            let tol = size * K::Real::epsilon() * max_singular_value(&svd);
+            let rank = svd.rank(tol);
+            (
+                svd.pinv(tol).mul_vec(b),
+                rank,
+                svd.singular_values().clone(),
+            )
+        };
+
+        if singular_values.iter().any(|s| s.is_nan()) {
+            return Err(Error::Singular.into());
+        }
+        let residual = (0..m)
+            .map(|i| b[i] - (0..n).fold(K::zero(), |acc, j| acc + a[(i, j)] * solution[j]))
+            .fold(K::Real::zero(), |acc, r| acc + r.modulus_sqr())
+            .sqrt();
+        Ok(LeastSquares {
+            solution,
+            residual,
+            rank,
+            singular_values,
+        })
+    }
+
+    /// Returns the solution `x` minimizing `‖Ax - b‖`.
+    pub fn solution(&self) -> &Vector<K> {
+        &self.solution
+    }
+
+    /// Returns the residual norm `‖Ax - b‖` of the solution.
+    pub fn residual(&self) -> K::Real {
+        self.residual
+    }
+
+    /// Returns the effective rank of `A`.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Returns the singular values of `A` in descending order.
+    pub fn singular_values(&self) -> &Vector<K::Real> {
+        &self.singular_values
+    }
+}
+
+fn max_singular_value<K: Scalar>(svd: &Svd<K>) -> K::Real {
+    svd.singular_values()
+        .first()
+        .copied()
+        .unwrap_or_else(K::Real::zero)
+}
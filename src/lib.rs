@@ -1,71 +1,285 @@
+pub mod banded;
+pub mod cholesky;
+pub mod error;
+pub mod gemm;
+pub mod iterative;
+pub mod ldlt;
+pub mod least_squares;
+pub mod lu;
 pub mod matrix;
+pub mod ordering;
+pub mod parallel;
+pub mod qr;
+pub mod schur;
+pub mod simd;
+pub mod smatrix;
+pub mod sparse;
+pub mod sparse_cholesky;
+pub mod sparse_lu;
+pub mod stationary;
+pub mod strassen;
+pub mod structured;
+pub mod svd;
+pub mod svector;
+pub mod symmetric_eigen;
 pub mod vector;
+pub mod view;
 
-use num::{pow::Pow, Float, NumCast};
+use matrix::Matrix;
+use num::{
+    traits::{float::FloatCore, NumAssign},
+    Complex,
+};
+use simd::Kernels;
 use std::{
-    iter::Sum,
-    ops::{AddAssign, MulAssign, SubAssign},
+    fmt::Debug,
+    iter::{Product, Sum},
+    ops::{Mul, Neg},
 };
+use vector::Vector;
 
-/// Trait bounds for `K`.
-pub trait Scalar<K>:
-    Sum<K>
-    + AddAssign<K>
-    + SubAssign<K>
-    + MulAssign<K>
+/// Elements of vectors and matrices: the real numbers `f32` and `f64`, which also implement
+/// [`RealScalar`], and the complex numbers `Complex<f32>` and `Complex<f64>` built on them. The
+/// methods of this trait are those that differ between the real and the complex field.
+pub trait Scalar:
+    NumAssign
+    + Neg<Output = Self>
+    + Sum
+    + Product
+    + Copy
     + Default
-    + NumCast
-    + From<f32>
-    + Into<f32>
-    + Pow<f32>
-    + Float
+    + Debug
+    + Send
+    + Sync
+    + Kernels
+    + 'static
 {
+    /// The real field underlying the scalar, which is `Self` for real scalars.
+    type Real: RealScalar;
+
+    /// Embeds a real number.
+    fn from_real(re: Self::Real) -> Self;
+
+    /// Returns the real part.
+    fn re(self) -> Self::Real;
+
+    /// Returns the imaginary part, which is zero for real scalars.
+    fn im(self) -> Self::Real;
+
+    /// Returns the complex conjugate, which is the scalar itself for real scalars.
+    fn conj(self) -> Self;
+
+    /// Returns the modulus `|x|`, which is the absolute value for real scalars.
+    fn modulus(self) -> Self::Real;
+
+    /// Returns the squared modulus `|x|²`, without taking a square root.
+    fn modulus_sqr(self) -> Self::Real;
+
+    /// Returns the principal square root, which is NaN for negative real scalars.
+    fn sqrt(self) -> Self;
 }
 
-impl<
-        K: Sum<K>
-            + AddAssign<K>
-            + SubAssign<K>
-            + MulAssign<K>
-            + Default
-            + NumCast
-            + From<f32>
-            + Into<f32>
-            + Pow<f32>
-            + Float,
-    > Scalar<K> for K
-{
+/// Real [`Scalar`]s, which are ordered and provide the usual floating-point operations.
+pub trait RealScalar: Scalar<Real = Self> + FloatCore {
+    /// Computes `sqrt(self² + other²)` without intermediate overflow or underflow.
+    fn hypot(self, other: Self) -> Self;
+}
+
+macro_rules! scalar {
+    ($real:ty) => {
+        impl Scalar for $real {
+            type Real = $real;
+
+            fn from_real(re: $real) -> Self {
+                re
+            }
+
+            fn re(self) -> $real {
+                self
+            }
+
+            fn im(self) -> $real {
+                0.
+            }
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn modulus(self) -> $real {
+                self.abs()
+            }
+
+            fn modulus_sqr(self) -> $real {
+                self * self
+            }
+
+            fn sqrt(self) -> Self {
+                <$real>::sqrt(self)
+            }
+        }
+
+        impl RealScalar for $real {
+            fn hypot(self, other: Self) -> Self {
+                <$real>::hypot(self, other)
+            }
+        }
+
+        impl Scalar for Complex<$real> {
+            type Real = $real;
+
+            fn from_real(re: $real) -> Self {
+                Complex::new(re, 0.)
+            }
+
+            fn re(self) -> $real {
+                self.re
+            }
+
+            fn im(self) -> $real {
+                self.im
+            }
+
+            fn conj(self) -> Self {
+                Complex::conj(&self)
+            }
+
+            fn modulus(self) -> $real {
+                self.norm()
+            }
+
+            fn modulus_sqr(self) -> $real {
+                self.norm_sqr()
+            }
+
+            fn sqrt(self) -> Self {
+                Complex::sqrt(self)
+            }
+        }
+    };
 }
 
-/// This macro enables the implementation of Add, AddAssign, Sub, SubAssign, Mul and MulAssign for Vector and Matrix.
+scalar!(f32);
+scalar!(f64);
+
+/// This macro enables the element-wise implementation of Add, AddAssign, Sub, SubAssign, Mul and
+/// MulAssign for Vector, for owned as well as borrowed operands.
 #[macro_export]
 macro_rules! arithmetic {
     ($struct:tt, Add) => {
-        arithmetic!($struct, Add, +=);
+        arithmetic!($struct, Add, add_elementwise);
     };
     ($struct:tt, Sub) => {
-        arithmetic!($struct, Sub, -=);
+        arithmetic!($struct, Sub, sub_elementwise);
     };
     ($struct:tt, Mul) => {
-        arithmetic!($struct, Mul, *=);
+        arithmetic!($struct, Mul, mul_elementwise);
     };
-    ($struct:ty, $trait:ty, $op:tt) => {
+    ($struct:tt, $trait:tt, $kernel:ident) => {
         paste::item! {
-        impl<K: Scalar<K>> [<$trait Assign>] for $struct<K> {
-            fn [<$trait:lower _assign>](&mut self, rhs: Self) {
-                self.iter_mut().zip_eq(rhs.iter()).for_each(|(u, v)| {
-                    *u $op v.clone();
+        impl<K: Scalar> [<$trait Assign>]<&$struct<K>> for $struct<K> {
+            fn [<$trait:lower _assign>](&mut self, rhs: &Self) {
+                let isa = $crate::simd::Isa::detect();
+                $crate::parallel::elementwise(self, rhs, |y, x| {
+                    <K as $crate::simd::Kernels>::$kernel(isa, y, x)
                 });
             }
+        }}
+        $crate::operators!($struct, $trait);
+    };
+}
+
+/// This macro implements a binary operator for every combination of owned and borrowed operands on
+/// top of its assigning counterpart with a borrowed right-hand side, e.g. `AddAssign<&Matrix>`.
+/// `&u op &v` clones `u`, while `&u op v` writes the result into `v`: `Sub` negates `v` first,
+/// and the other operators are commutative.
+#[macro_export]
+macro_rules! operators {
+    (@into_rhs Sub, $lhs:expr, $rhs:expr) => {{
+        let mut rhs = -$rhs;
+        rhs += $lhs;
+        rhs
+    }};
+    (@into_rhs $trait:tt, $lhs:expr, $rhs:expr) => {{
+        let mut rhs = $rhs;
+        paste::item! { rhs.[<$trait:lower _assign>]($lhs) };
+        rhs
+    }};
+    ($struct:tt, $trait:tt) => {
+        paste::item! {
+        impl<K: Scalar> [<$trait Assign>] for $struct<K> {
+            fn [<$trait:lower _assign>](&mut self, rhs: Self) {
+                self.[<$trait:lower _assign>](&rhs);
+            }
+        }
+        impl<K: Scalar> $trait<&$struct<K>> for $struct<K> {
+            type Output = $struct<K>;
+
+            fn [<$trait:lower>](mut self, rhs: &$struct<K>) -> Self::Output {
+                self.[<$trait:lower _assign>](rhs);
+                self
+            }
+        }
+        impl<K: Scalar> $trait for $struct<K> {
+            type Output = $struct<K>;
+
+            fn [<$trait:lower>](self, rhs: Self) -> Self::Output {
+                self.[<$trait:lower>](&rhs)
+            }
+        }
+        impl<K: Scalar> $trait<&$struct<K>> for &$struct<K> {
+            type Output = $struct<K>;
+
+            fn [<$trait:lower>](self, rhs: &$struct<K>) -> Self::Output {
+                self.clone().[<$trait:lower>](rhs)
+            }
         }
-        impl<K: Scalar<K>> $trait for $struct<K> {
-            type Output = Self;
+        impl<K: Scalar> $trait<$struct<K>> for &$struct<K> {
+            type Output = $struct<K>;
 
-            fn [<$trait:lower>](self, f: Self) -> Self::Output {
-                let mut res = self;
-                res.[<$trait:lower _assign>](f);
-                res
+            fn [<$trait:lower>](self, rhs: $struct<K>) -> Self::Output {
+                $crate::operators!(@into_rhs $trait, self, rhs)
             }
         }}
-    }
+    };
 }
+
+/// Implements multiplication of Vector and Matrix by a scalar on the left. It is spelled out per
+/// scalar type, because a foreign type parameter cannot implement `Mul<Matrix<K>>`.
+macro_rules! scalar_on_left {
+    ($($scalar:ty),*) => {$(
+        impl Mul<Vector<$scalar>> for $scalar {
+            type Output = Vector<$scalar>;
+
+            fn mul(self, rhs: Vector<$scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<&Vector<$scalar>> for $scalar {
+            type Output = Vector<$scalar>;
+
+            fn mul(self, rhs: &Vector<$scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<Matrix<$scalar>> for $scalar {
+            type Output = Matrix<$scalar>;
+
+            fn mul(self, rhs: Matrix<$scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<&Matrix<$scalar>> for $scalar {
+            type Output = Matrix<$scalar>;
+
+            fn mul(self, rhs: &Matrix<$scalar>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+scalar_on_left!(f32, f64, Complex<f32>, Complex<f64>);
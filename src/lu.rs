@@ -0,0 +1,175 @@
+use crate::{error::Error, matrix::Matrix, parallel, vector::Vector, view::MatrixView, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+
+/// LU decomposition `PA = LU` of a square [`Matrix`] computed with partial pivoting. `L` is unit
+/// lower triangular, `U` is upper triangular and `P` is stored as the row permutation that was
+/// applied to `A`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lu<K> {
+    l: Matrix<K>,
+    u: Matrix<K>,
+    permutation: Vec<usize>,
+    sign: K,
+    singular: bool,
+}
+
+impl<K: Scalar> Lu<K> {
+    /// Factorizes the given square [`Matrix`]. A singular [`Matrix`] can be factorized, but
+    /// [`Lu::solve`] and [`Lu::inverse`] will return an error for it. A pivot counts as zero when
+    /// it is below `n * ε` times the largest entry of its original row. Returns
+    /// [`Error::Singular`] if a NaN turns up in a pivot column, since no pivot can be chosen.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>) -> Result<Lu<K>>
+    where
+        K: 'a,
+    {
+        let mut u = a.into().to_matrix();
+        if u.m != u.n {
+            return Err(Error::NotSquare {
+                rows: u.m,
+                columns: u.n,
+            }
+            .into());
+        }
+        let n = u.n;
+        let mut l = Matrix::identity(n);
+        let mut permutation = (0..n).collect::<Vec<_>>();
+        let mut sign = K::one();
+        let tolerances: Vec<K::Real> = u
+            .row_iter()
+            .map(|row| {
+                row.iter()
+                    .fold(K::Real::zero(), |max, x| max.max(x.modulus()))
+                    * K::Real::epsilon()
+                    * <K::Real as NumCast>::from(n).unwrap()
+            })
+            .collect();
+
+        for k in 0..n {
+            if (k..n).any(|i| u[i][k].modulus().is_nan()) {
+                return Err(Error::Singular.into());
+            }
+            let p = (k..n)
+                .max_by(|&a, &b| u[a][k].modulus().partial_cmp(&u[b][k].modulus()).unwrap())
+                .unwrap();
+            if p != k {
+                u.swap_rows(p, k);
+                permutation.swap(p, k);
+                sign = -sign;
+                for j in 0..k {
+                    let tmp = l[p][j];
+                    l[p][j] = l[k][j];
+                    l[k][j] = tmp;
+                }
+            }
+            if u[k][k] == K::default() {
+                continue;
+            }
+            // The rows below the pivot are eliminated independently, keeping their ratio in
+            // column `k` until it is moved to `L`.
+            let (top, rest) = u.as_mut_slice().split_at_mut((k + 1) * n);
+            let pivot = &top[k * n..];
+            parallel::for_each_chunk(rest, n, (n - k) * (n - k), |_, row| {
+                let ratio = row[k] / pivot[k];
+                row[k] = ratio;
+                for j in (k + 1)..n {
+                    let tmp = pivot[j] * ratio;
+                    row[j] -= tmp;
+                }
+            });
+            for i in (k + 1)..n {
+                l[i][k] = u[i][k];
+                u[i][k] = K::default();
+            }
+        }
+        let singular = (0..n).any(|k| u[k][k].modulus() <= tolerances[permutation[k]]);
+        Ok(Lu {
+            l,
+            u,
+            permutation,
+            sign,
+            singular,
+        })
+    }
+
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    /// Returns the row permutation `P`: row `i` of `PA` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Solves `Ax = b` for `x` using the factorization.
+    /// ```
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let lu = Matrix::from([[2., 1.], [4., 3.]]).lu().unwrap();
+    /// assert_eq!(lu.solve(&Vector::from([3., 7.])).unwrap(), Vector::from([1., 1.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.u.n;
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        if self.singular {
+            return Err(Error::Singular.into());
+        }
+        let mut x: Vector<K> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                let tmp = self.l[i][j] * x[j];
+                x[i] -= tmp;
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                let tmp = self.u[i][j] * x[j];
+                x[i] -= tmp;
+            }
+            x[i] /= self.u[i][i];
+        }
+        Ok(x)
+    }
+
+    /// Solves `AX = B` for `X`, treating every column of `B` as a separate right-hand side.
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Matrix<K>> {
+        if b.m != self.u.n {
+            return Err(Error::DimensionMismatch {
+                expected: self.u.n,
+                found: b.m,
+            }
+            .into());
+        }
+        Ok(b.transpose()
+            .row_iter()
+            .map(|column| self.solve(&column.into()))
+            .collect::<Result<Matrix<K>>>()?
+            .transpose())
+    }
+
+    /// Checks whether the factorized [`Matrix`] is singular or numerically close to singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Computes the determinant of the factorized [`Matrix`].
+    pub fn determinant(&self) -> K {
+        (0..self.u.n).fold(self.sign, |determinant, i| determinant * self.u[i][i])
+    }
+
+    /// Computes the inverse of the factorized [`Matrix`].
+    pub fn inverse(&self) -> Result<Matrix<K>> {
+        self.solve_many(&Matrix::identity(self.u.n))
+    }
+}
@@ -1,69 +1,296 @@
-use crate::{arithmetic, vector::Vector, Scalar};
-use anyhow::{anyhow, Result};
-use derive_more::{Deref, DerefMut, Index, IndexMut};
-use itertools::Itertools;
-use num::pow::Pow;
+use crate::{
+    cholesky::Cholesky,
+    error::Error,
+    ldlt::Ldlt,
+    least_squares::LeastSquares,
+    lu::Lu,
+    operators, parallel,
+    qr::Qr,
+    schur::{Hessenberg, Schur},
+    simd::Isa,
+    strassen::Algorithm,
+    svd::Svd,
+    symmetric_eigen::{SymmetricEigen, DEFAULT_MAX_SWEEPS},
+    vector::Vector,
+    view::{MatrixView, MatrixViewMut},
+    RealScalar, Scalar,
+};
+use anyhow::Result;
+use num::{traits::float::FloatCore, Complex, Zero};
 use std::{
     fmt,
-    iter::Sum,
-    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, RangeBounds, Sub, SubAssign},
 };
 
-/// Matrix struct that consists of a `Vec` of [`Vector`]'s and it's shape.
-#[derive(Clone, Debug, Default, Deref, DerefMut, Index, IndexMut, PartialEq)]
+/// Matrix struct that stores its `m` rows of `n` elements in a single contiguous buffer, in
+/// row-major order. Element `(i, j)` lives at offset `i * n + j`, see [`Matrix::strides`].
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Matrix<K> {
-    #[deref]
-    #[deref_mut]
-    #[index]
-    #[index_mut]
-    pub matrix: Vec<Vector<K>>,
-    pub n: usize,
-    pub m: usize,
+    data: Vec<K>,
+    pub(crate) n: usize,
+    pub(crate) m: usize,
+}
+
+impl<K> Matrix<K> {
+    /// Creates a [`Matrix`] of `m` rows and `n` columns from its elements in row-major order.
+    /// Returns an error if `data` does not hold exactly `m * n` elements.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from_vec(2, 3, vec![1., 2., 3., 4., 5., 6.]).unwrap();
+    /// assert_eq!(u, Matrix::from([[1., 2., 3.], [4., 5., 6.]]));
+    /// assert!(Matrix::from_vec(2, 2, vec![1., 2., 3.]).is_err());
+    /// ```
+    pub fn from_vec(m: usize, n: usize, data: Vec<K>) -> Result<Matrix<K>> {
+        if data.len() != m * n {
+            return Err(Error::DimensionMismatch {
+                expected: m * n,
+                found: data.len(),
+            }
+            .into());
+        }
+        Ok(Matrix { data, n, m })
+    }
+
+    /// Returns the number of rows `m`.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns `n`.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the distance in the underlying buffer between consecutive rows and between
+    /// consecutive columns.
+    pub fn strides(&self) -> (usize, usize) {
+        (self.n, 1)
+    }
+
+    /// Returns all elements in row-major order.
+    pub fn as_slice(&self) -> &[K] {
+        &self.data
+    }
+
+    /// Returns all elements in row-major order, mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [K] {
+        &mut self.data
+    }
+
+    /// Iterates over the rows of the current [`Matrix`] as slices.
+    pub fn row_iter(&self) -> impl Iterator<Item = &[K]> {
+        (0..self.m).map(move |i| &self[i])
+    }
+
+    /// Iterates over the rows of the current [`Matrix`] as mutable slices.
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut [K]> {
+        self.data.chunks_exact_mut(self.n.max(1))
+    }
+
+    /// Borrows the whole [`Matrix`] as a [`MatrixView`].
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        self.into()
+    }
+
+    /// Borrows the whole [`Matrix`] as a [`MatrixViewMut`].
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, K> {
+        self.into()
+    }
+
+    /// Borrows the block spanned by the given row and column ranges without copying it.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    /// assert_eq!(u.view(.., 1..).to_matrix(), Matrix::from([[2., 3.], [5., 6.]]));
+    /// ```
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, K> {
+        self.as_view().view(rows, columns)
+    }
+
+    /// Mutably borrows the block spanned by the given row and column ranges, so that it can be
+    /// updated in place.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let mut u = Matrix::from([[1., 2.], [3., 4.]]);
+    /// let v = Matrix::from([[10., 20.]]);
+    /// u.view_mut(1..2, ..).add_assign(&v);
+    /// assert_eq!(u, Matrix::from([[1., 2.], [13., 24.]]));
+    /// # use std::ops::AddAssign;
+    /// ```
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, K> {
+        self.as_view_mut().into_view(rows, columns)
+    }
+
+    /// Borrows row `i` as a `1`x`n` [`MatrixView`].
+    pub fn row(&self, i: usize) -> MatrixView<'_, K> {
+        self.as_view().row(i)
+    }
+
+    /// Borrows column `j` as an `m`x`1` [`MatrixView`].
+    /// ```
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let u = Matrix::from([[1., 2.], [3., 4.]]);
+    /// assert_eq!(u.col(1).to_vector(), Vector::from([2., 4.]));
+    /// ```
+    pub fn col(&self, j: usize) -> MatrixView<'_, K> {
+        self.as_view().col(j)
+    }
+
+    /// Borrows the main diagonal as a `min(m, n)`x`1` [`MatrixView`].
+    pub fn diag(&self) -> MatrixView<'_, K> {
+        self.as_view().diag()
+    }
+
+    /// Mutably borrows row `i` as a `1`x`n` [`MatrixViewMut`].
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, K> {
+        self.view_mut(i..=i, ..)
+    }
+
+    /// Mutably borrows column `j` as an `m`x`1` [`MatrixViewMut`].
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, K> {
+        self.view_mut(.., j..=j)
+    }
+
+    /// Mutably borrows the main diagonal as a `min(m, n)`x`1` [`MatrixViewMut`].
+    pub fn diag_mut(&mut self) -> MatrixViewMut<'_, K> {
+        self.as_view_mut().into_diag()
+    }
+
+    /// Swaps rows `a` and `b` of the current [`Matrix`].
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a != b {
+            (0..self.n).for_each(|j| self.data.swap(a * self.n + j, b * self.n + j));
+        }
+    }
 }
 
-impl<K: Scalar<K>> Matrix<K>
-where
-    f32: Sum<K> + Sum<<K as Pow<f32>>::Output>,
-{
+impl<K: Scalar> Matrix<K> {
     pub fn shape(&self) -> (usize, usize) {
         (self.n, self.m)
     }
 
-    /// Multiplies this [`Matrix`] by the given [`Vector`].
+    /// Checks whether the current [`Matrix`] is square and equal to its transpose, up to rounding
+    /// errors.
+    pub fn is_symmetric(&self) -> bool {
+        self.as_view().is_symmetric()
+    }
+
+    /// Checks whether the current [`Matrix`] is square and equal to its conjugate transpose, up to
+    /// rounding errors. For real matrices this is the same as [`Matrix::is_symmetric`].
+    pub fn is_hermitian(&self) -> bool {
+        self.as_view().is_hermitian()
+    }
+
+    /// Creates a [`Matrix`] of `m` rows and `n` columns filled with zeros.
+    pub fn zeros(m: usize, n: usize) -> Matrix<K> {
+        Matrix {
+            data: vec![K::zero(); m * n],
+            n,
+            m,
+        }
+    }
+
+    /// Creates the identity [`Matrix`] of shape `n`x`n`.
+    pub fn identity(n: usize) -> Matrix<K> {
+        let mut res = Matrix::zeros(n, n);
+        (0..n).for_each(|i| res[i][i] = K::one());
+        res
+    }
+
+    /// Multiplies this [`Matrix`] by the given [`Vector`], which is also available as `&u * &v`.
     /// ```
-    /// let mut u = Matrix::from([[2., 0.], [0., 2.]]);
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let u = Matrix::from([[2., 0.], [0., 2.]]);
     /// let v = Vector::from([4., 2.]);
     /// assert_eq!(u.mul_vec(&v), Vector::from([8., 4.]));
+    /// assert_eq!(&u * &v, Vector::from([8., 4.]));
     /// ```
-    pub fn mul_vec(&mut self, vec: &Vector<K>) -> Vector<K> {
-        self.iter()
-            .map(|v| (v.clone() * vec.clone()).vector.into_iter().sum())
-            .collect()
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        let (isa, n) = (Isa::detect(), self.n);
+        let mut res = vec![K::zero(); self.m];
+        parallel::for_each_chunk(&mut res, 1, self.m * n, |i, out| {
+            out[0] = K::dot(isa, &self.data[i * n..(i + 1) * n], vec)
+        });
+        Vector::from(res)
     }
 
-    /// Multiplies this [`Matrix`] by the given `Matrix`.
+    /// Multiplies this [`Matrix`] by the given `Matrix` or view. The product of two matrices is
+    /// also available as `&u * &v`.
     /// ```
-    /// let mut u = Matrix::from([[3., -5.], [6., 8.]]);
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[3., -5.], [6., 8.]]);
     /// let v = Matrix::from([[2., 1.], [4., 2.]]);
     /// assert_eq!(u.mul_mat(&v), Matrix::from([[-14., -7.], [44., 22.]]));
+    /// assert_eq!(&u * &v, Matrix::from([[-14., -7.], [44., 22.]]));
+    /// ```
+    pub fn mul_mat<'a>(&self, mat: impl Into<MatrixView<'a, K>>) -> Matrix<K>
+    where
+        K: 'a,
+    {
+        self.as_view().mul_mat(mat)
+    }
+
+    /// Multiplies this [`Matrix`] by the given `Matrix` or view with the chosen [`Algorithm`],
+    /// which lets very large products opt into Strassen–Winograd recursion.
+    /// ```
+    /// # use matrix::{matrix::Matrix, strassen::{Algorithm, DEFAULT_CUTOFF}};
+    /// let u = Matrix::from([[3., -5.], [6., 8.]]);
+    /// let v = Matrix::from([[2., 1.], [4., 2.]]);
+    /// let strassen = Algorithm::Strassen { cutoff: DEFAULT_CUTOFF };
+    /// assert_eq!(u.mul_mat_with(&v, strassen), Matrix::from([[-14., -7.], [44., 22.]]));
     /// ```
-    pub fn mul_mat(&mut self, mat: &Matrix<K>) -> Matrix<K> {
-        self.iter()
-            .map(|v| mat.transpose().iter().map(|u| u.dot(v.clone())).collect())
-            .collect()
+    pub fn mul_mat_with<'a>(
+        &self,
+        mat: impl Into<MatrixView<'a, K>>,
+        algorithm: Algorithm,
+    ) -> Matrix<K>
+    where
+        K: 'a,
+    {
+        self.as_view().mul_mat_with(mat, algorithm)
+    }
+
+    /// Multiplies this [`Matrix`] by the given `Matrix` or view and writes the product into
+    /// `out`, which must already have the shape of the product.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[3., -5.], [6., 8.]]);
+    /// let mut out = Matrix::zeros(2, 2);
+    /// u.mul_mat_into(&Matrix::from([[2., 1.], [4., 2.]]), &mut out);
+    /// assert_eq!(out, Matrix::from([[-14., -7.], [44., 22.]]));
+    /// ```
+    pub fn mul_mat_into<'a, 'b>(
+        &self,
+        mat: impl Into<MatrixView<'a, K>>,
+        out: impl Into<MatrixViewMut<'b, K>>,
+    ) where
+        K: 'a + 'b,
+    {
+        self.as_view().mul_mat_into(mat, out)
     }
 
     /// Computes the trace of the current [`Matrix`].
     /// ```
-    /// let mut u = Matrix::from([[2., -5., 0.], [4., 3., 7.], [-2., 3., 4.]]);
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[2., -5., 0.], [4., 3., 7.], [-2., 3., 4.]]);
     /// assert_eq!(u.trace(), 9.0);
     /// ```
-    pub fn trace(&mut self) -> K {
+    pub fn trace(&self) -> K {
         (0..self.n).map(|idx| self[idx][idx]).sum()
     }
 
     /// Computes and returns the transpose matrix of the current [`Matrix`].
     /// ```
+    /// # use matrix::matrix::Matrix;
     /// let u = Matrix::from([[1., 2., 3.], [3., 4., 5.], [6., 7., 8.]]);
     /// assert_eq!(
     ///     u.transpose(),
@@ -71,50 +298,35 @@ where
     /// );
     /// ```
     pub fn transpose(&self) -> Matrix<K> {
-        let mut iters: Vec<_> = self.iter().map(|n| n.iter()).collect();
-
-        (0..self[0].len())
-            .map(|_| iters.iter_mut().map(|n| *n.next().unwrap()).collect())
-            .collect()
+        let (m, n) = (self.m, self.n);
+        let mut data = vec![K::zero(); m * n];
+        parallel::for_each_chunk(&mut data, m, m * n, |j, row| {
+            row.iter_mut()
+                .enumerate()
+                .for_each(|(i, x)| *x = self.data[i * n + j])
+        });
+        Matrix { data, n: m, m: n }
     }
 
-    fn row_echelon(&mut self) -> Matrix<K> {
-        let (nrows, ncols) = self.shape();
-        let (mut pivot_row, mut pivot_col) = (0, 0);
-        let mut res = self.clone();
-
-        while pivot_row < nrows - 1 && pivot_col < ncols - 1 {
-            let (_, i_max) = (pivot_row..nrows).fold((f32::MIN.into(), 0), |(max, i_max), i| {
-                if res[i][pivot_col].abs() > max {
-                    (res[i][pivot_col], i)
-                } else {
-                    (max, i_max)
-                }
-            });
-            if res[i_max][pivot_col] != K::default() {
-                for j in 0..res.m {
-                    let tmp = res[i_max][j];
-                    res[i_max][j] = res[pivot_row][j];
-                    res[pivot_row][j] = tmp;
-                }
-                for i in (pivot_row + 1)..nrows {
-                    let ratio = res[i][pivot_col] / res[pivot_row][pivot_col];
-                    res[i][pivot_col] = K::default();
-                    for j in (pivot_row + 1)..ncols {
-                        let tmp = res[pivot_row][j] * ratio;
-                        res[i][j] -= tmp;
-                    }
-                }
-                pivot_col += 1;
-            }
-            pivot_row += 1;
-        }
-        res
+    /// Computes and returns the conjugate transpose `Aᴴ` of the current [`Matrix`], which is its
+    /// transpose with every element conjugated. For real matrices this is [`Matrix::transpose`].
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// # use num::Complex;
+    /// let u = Matrix::from([[Complex::new(1., 2.), Complex::new(0., -1.)]]);
+    /// assert_eq!(
+    ///     u.conjugate_transpose(),
+    ///     Matrix::from([[Complex::new(1., -2.)], [Complex::new(0., 1.)]])
+    /// );
+    /// ```
+    pub fn conjugate_transpose(&self) -> Matrix<K> {
+        self.as_view().conjugate_transpose()
     }
 
     /// Computes the reduced row-echelon form of the current [`Matrix`].
     /// ```
-    /// let mut u = Matrix::from([
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::<f32>::from([
     ///     [8., 5., -2., 4., 28.],
     ///     [4., 2.5, 20., 4., -4.],
     ///     [8., 5., 1., 4., 17.],
@@ -128,7 +340,7 @@ where
     ///     ])
     /// );
     /// ```
-    pub fn reduced_row_echelon(&mut self) -> Matrix<K> {
+    pub fn reduced_row_echelon(&self) -> Matrix<K> {
         let mut pivot = 0;
         let mut res = self.clone();
         for r in 0..res.m {
@@ -136,7 +348,7 @@ where
                 return res;
             }
             let mut i = r;
-            while res[i][pivot] == 0.0.into() {
+            while res[i][pivot] == K::zero() {
                 i += 1;
                 if i == res.m {
                     i = r;
@@ -147,9 +359,9 @@ where
                 }
             }
             let divisor = res[r][pivot];
-            if divisor != 0.0.into() {
+            if divisor != K::zero() {
                 for j in 0..res.n {
-                    res[r][j] = res[r][j] / divisor;
+                    res[r][j] /= divisor;
                 }
             }
             for j in 0..res.m {
@@ -165,160 +377,501 @@ where
         res
     }
 
-    /// Calculates the determinant of a [`Matrix`]. Currently only for matrices up to shape 4x4.
+    /// Calculates the determinant of a square [`Matrix`]. Matrices up to shape 3x3 are expanded by
+    /// cofactors, larger ones through their [`Lu`] decomposition. Returns an error if the [`Matrix`] is
+    /// not square.
     /// ```
-    /// let mut u = Matrix::from([
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([
     ///     [8., 5., -2., 4.],
     ///     [4., 2.5, 20., 4.],
     ///     [8., 5., 1., 4.],
     ///     [28., -4., 17., 1.],
     /// ]);
-    /// assert_eq!(u.determinant(), 1032.0);
-    /// ```
-    pub fn determinant(&mut self) -> K {
-        match self.shape() {
-            (1, 1) => self[0][0],
-            (2, 2) => self[0][0] * self[1][1] - self[0][1] * self[1][0],
-            (3, 3) => {
-                self[0][0]
-                    * Matrix::from([
-                        ([self[1][1], self[1][2]]).into(),
-                        ([self[2][1], self[2][2]]).into(),
-                    ])
-                    .determinant()
-                    - self[0][1]
-                        * Matrix::from([
-                            ([self[1][0], self[1][2]]).into(),
-                            ([self[2][0], self[2][2]]).into(),
-                        ])
-                        .determinant()
-                    + self[0][2]
-                        * Matrix::from([
-                            ([self[1][0], self[1][1]]).into(),
-                            ([self[2][0], self[2][1]]).into(),
-                        ])
-                        .determinant()
-            }
-            (4, 4) => {
-                let res = self.clone().row_echelon();
-                (0..res.n).fold(1.0.into(), |determinant, i| determinant * res[i][i])
+    /// assert_eq!(u.determinant().unwrap(), 1032.0);
+    /// ```
+    pub fn determinant(&self) -> Result<K> {
+        if self.m != self.n {
+            return Err(Error::NotSquare {
+                rows: self.m,
+                columns: self.n,
             }
-            _ => panic!("Cannot calculate determinant"),
+            .into());
+        }
+        let a = self;
+        match self.n {
+            0 => Ok(K::one()),
+            1 => Ok(a[0][0]),
+            2 => Ok(a[0][0] * a[1][1] - a[0][1] * a[1][0]),
+            3 => Ok(a[0][0] * (a[1][1] * a[2][2] - a[1][2] * a[2][1])
+                - a[0][1] * (a[1][0] * a[2][2] - a[1][2] * a[2][0])
+                + a[0][2] * (a[1][0] * a[2][1] - a[1][1] * a[2][0])),
+            _ => Ok(self.lu()?.determinant()),
         }
     }
 
-    fn augmented(&mut self) -> Matrix<K> {
-        let mut res = self.clone();
-        for j in 0..self.m {
-            for i in 0..self.n {
-                res[j].push(if j == i { 1.0.into() } else { 0.0.into() });
+    /// Calculates the inverse of the [`Matrix`] through its [`Lu`] decomposition. Returns an error if
+    /// the [`Matrix`] is not square or singular.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[2., 0.], [0., 4.]]);
+    /// assert_eq!(u.inverse().unwrap(), Matrix::from([[0.5, 0.], [0., 0.25]]));
+    /// assert!(Matrix::from([[1., 2.], [2., 4.]]).inverse().is_err());
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<K>> {
+        self.lu()?.inverse()
+    }
+
+    /// Solves the linear system `Ax = b` for `x` without forming the inverse. Hermitian
+    /// positive-definite matrices are solved through their [`Cholesky`] decomposition, all others
+    /// through their [`Lu`] decomposition. Returns an [`Error`] if the [`Matrix`] is not square,
+    /// `b` has the wrong length or the [`Matrix`] is (numerically) singular.
+    /// ```
+    /// # use matrix::{error::Error, matrix::Matrix, vector::Vector};
+    /// let u = Matrix::from([[2., 1.], [4., 3.]]);
+    /// assert_eq!(u.solve(&Vector::from([3., 7.])).unwrap(), Vector::from([1., 1.]));
+    ///
+    /// let err = u.solve(&Vector::from([1., 2., 3.])).unwrap_err();
+    /// assert_eq!(
+    ///     err.downcast_ref::<Error>(),
+    ///     Some(&Error::DimensionMismatch { expected: 2, found: 3 })
+    /// );
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        self.check_solve_shape(b.len())?;
+        if let Some(cholesky) = self.spd_cholesky() {
+            return cholesky.solve(b);
+        }
+        self.lu()?.solve(b)
+    }
+
+    /// Solves the linear system `AX = B` for `X`, treating every column of `B` as a separate
+    /// right-hand side. See [`Matrix::solve`].
+    pub fn solve_many(&self, b: &Matrix<K>) -> Result<Matrix<K>> {
+        self.check_solve_shape(b.m)?;
+        if let Some(cholesky) = self.spd_cholesky() {
+            return cholesky.solve_many(b);
+        }
+        self.lu()?.solve_many(b)
+    }
+
+    fn check_solve_shape(&self, rows: usize) -> Result<()> {
+        if self.m != self.n {
+            return Err(Error::NotSquare {
+                rows: self.m,
+                columns: self.n,
             }
+            .into());
         }
-        res.n *= 2;
-        res
+        if rows != self.m {
+            return Err(Error::DimensionMismatch {
+                expected: self.m,
+                found: rows,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    fn spd_cholesky(&self) -> Option<Cholesky<K>> {
+        self.is_hermitian().then(|| self.cholesky().ok()).flatten()
     }
 
-    /// Calculates the inverse of the [`Matrix`].
+    /// Solves the linear least-squares problem `min ‖Ax - b‖`, returning the solution together with
+    /// its residual norm, the effective rank and the singular values of the current [`Matrix`].
     /// ```
-    /// let mut u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]]);
-    /// if let Ok(inverse) = u.inverse() {
-    ///     assert_eq!(
-    ///         inverse,
-    ///         Matrix::from([
-    ///             [0.649425287, 0.097701149, -0.655172414],
-    ///             [-0.781609195, -0.126436782, 0.965517241],
-    ///             [0.143678161, 0.0747126454, -0.206896552]
-    ///         ])
-    ///     );
-    /// }
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let u = Matrix::<f32>::from([[1., 0.], [1., 1.], [1., 2.]]);
+    /// let lstsq = u.lstsq(&Vector::from([1., 3., 5.])).unwrap();
+    /// assert!((lstsq.solution()[0] - 1.).abs() < 1e-5);
+    /// assert!((lstsq.solution()[1] - 2.).abs() < 1e-5);
+    /// assert_eq!(lstsq.rank(), 2);
     /// ```
-    pub fn inverse(&mut self) -> Result<Matrix<K>> {
-        if self.determinant() == 0.0.into() {
-            return Err(anyhow!("Matrix is singular"));
-        }
-        let reduced = self.augmented().reduced_row_echelon();
-        Ok((0..self.m)
-            .map(|j| (0..self.n).map(|i| reduced[j][i + self.n]).collect())
-            .collect())
+    pub fn lstsq(&self, b: &Vector<K>) -> Result<LeastSquares<K>> {
+        LeastSquares::new(self, b)
+    }
+
+    /// Computes the [`Lu`] decomposition with partial pivoting of the current [`Matrix`], which can be
+    /// reused to solve for many right-hand sides.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let lu = Matrix::from([[2., 1.], [4., 3.]]).lu().unwrap();
+    /// assert_eq!(lu.determinant(), 2.0);
+    /// ```
+    pub fn lu(&self) -> Result<Lu<K>> {
+        Lu::new(self)
+    }
+
+    /// Computes the [`Cholesky`] decomposition of the current [`Matrix`]. Returns an error if the
+    /// [`Matrix`] is not Hermitian positive-definite.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let cholesky = Matrix::from([[4., 2.], [2., 5.]]).cholesky().unwrap();
+    /// assert_eq!(cholesky.l(), &Matrix::from([[2., 0.], [1., 2.]]));
+    /// assert!(Matrix::from([[1., 2.], [2., 1.]]).cholesky().is_err());
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky<K>> {
+        Cholesky::new(self)
+    }
+
+    /// Computes the [`Ldlt`] decomposition of the current Hermitian [`Matrix`], which does not need
+    /// to be positive-definite.
+    pub fn ldlt(&self) -> Result<Ldlt<K>> {
+        Ldlt::new(self)
+    }
+
+    /// Computes the [`Qr`] decomposition of the current [`Matrix`] using Householder reflections.
+    /// Works for matrices of any shape.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let qr = Matrix::from([[3., 1.], [4., 2.], [0., 2.]]).qr();
+    /// assert_eq!(qr.q().shape(), (3, 3));
+    /// assert_eq!(qr.r()[2][1], 0.);
+    /// ```
+    pub fn qr(&self) -> Qr<K> {
+        Qr::householder(self)
+    }
+
+    /// Computes the [`Qr`] decomposition of the current [`Matrix`] using Givens rotations.
+    pub fn qr_givens(&self) -> Qr<K> {
+        Qr::givens(self)
     }
 
-    /// Cmputes the rank of the current [`Matrix`].
+    /// Computes the numerical rank of the current [`Matrix`], i.e. the number of singular values
+    /// larger than `max(m, n) * ε * σ_max`.
     /// ```
-    /// let mut u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.], [21., 18., 7.]]);
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[8., 5., -2.], [4., 7., 20.], [7., 6., 1.], [21., 18., 7.]]);
     /// assert_eq!(u.rank(), 3);
     /// ```
-    pub fn rank(&mut self) -> usize {
-        (self.clone().reduced_row_echelon())
+    pub fn rank(&self) -> usize {
+        let svd = self.svd();
+        svd.rank(svd.default_tolerance())
+    }
+
+    /// Computes the number of singular values of the current [`Matrix`] larger than `tol`.
+    pub fn rank_with_tol(&self, tol: K::Real) -> usize {
+        self.svd().rank(tol)
+    }
+
+    /// Computes the [`Svd`] of the current [`Matrix`] using the one-sided Jacobi method.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let svd = Matrix::from([[3., 0.], [0., -4.]]).svd();
+    /// assert_eq!(svd.singular_values()[0], 4.);
+    /// assert_eq!(svd.singular_values()[1], 3.);
+    /// ```
+    pub fn svd(&self) -> Svd<K> {
+        Svd::new(self)
+    }
+
+    /// Computes the [`SymmetricEigen`] decomposition of the current Hermitian [`Matrix`], with the
+    /// real eigenvalues sorted in ascending order. Returns an error if the [`Matrix`] is not
+    /// Hermitian.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let eigen = Matrix::<f32>::from([[2., 1.], [1., 2.]]).symmetric_eigen().unwrap();
+    /// assert!((eigen.eigenvalues()[0] - 1.).abs() < 1e-6);
+    /// assert!((eigen.eigenvalues()[1] - 3.).abs() < 1e-6);
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<K>> {
+        SymmetricEigen::new(self, K::Real::epsilon(), DEFAULT_MAX_SWEEPS)
+    }
+
+    /// Computes the [`SymmetricEigen`] decomposition of the current Hermitian [`Matrix`] with a
+    /// custom relative convergence tolerance. Returns an error if the iteration has not converged
+    /// after `max_sweeps` sweeps.
+    pub fn symmetric_eigen_with_tol(
+        &self,
+        tol: K::Real,
+        max_sweeps: usize,
+    ) -> Result<SymmetricEigen<K>> {
+        SymmetricEigen::new(self, tol, max_sweeps)
+    }
+
+    /// Computes the [`Hessenberg`] decomposition of the current square [`Matrix`].
+    pub fn hessenberg(&self) -> Result<Hessenberg<K>> {
+        Hessenberg::new(self)
+    }
+
+    /// Computes the [`Schur`] decomposition of the current square [`Matrix`], including the Schur
+    /// vectors. Real matrices get their real Schur form.
+    pub fn schur(&self) -> Result<Schur<K>> {
+        Schur::new(self, true)
+    }
+
+    /// Computes the possibly complex eigenvalues of the current square [`Matrix`] via its
+    /// [`Schur`] form. For real matrices complex conjugate pairs are adjacent.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// # use num::Complex;
+    /// let u = Matrix::from([[0., -1.], [1., 0.]]);
+    /// assert_eq!(
+    ///     u.eigenvalues().unwrap(),
+    ///     vec![Complex::new(0., 1.), Complex::new(0., -1.)]
+    /// );
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vec<Complex<K::Real>>> {
+        Ok(Schur::new(self, false)?.eigenvalues())
+    }
+
+    /// Computes the spectral radius of the current square [`Matrix`], the largest modulus of its
+    /// eigenvalues.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[0., -2.], [2., 0.]]);
+    /// assert_eq!(u.spectral_radius().unwrap(), 2.);
+    /// ```
+    pub fn spectral_radius(&self) -> Result<K::Real> {
+        Ok(self
+            .eigenvalues()?
             .iter()
-            .filter(|r| r.iter().any(|n| *n != Default::default()))
-            .count()
+            .fold(K::Real::zero(), |max, lambda| {
+                max.max(lambda.re.hypot(lambda.im))
+            }))
+    }
+
+    /// Computes the singular values of the current [`Matrix`] in descending order.
+    pub fn singular_values(&self) -> Vector<K::Real> {
+        self.svd().singular_values().clone()
+    }
+
+    /// Computes the Moore–Penrose pseudo-inverse of the current [`Matrix`], which also exists for
+    /// singular and rectangular matrices.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[2., 0.], [0., 0.]]);
+    /// assert_eq!(u.pinv(), Matrix::from([[0.5, 0.], [0., 0.]]));
+    /// ```
+    pub fn pinv(&self) -> Matrix<K> {
+        let svd = self.svd();
+        svd.pinv(svd.default_tolerance())
+    }
+}
+
+impl<K> Index<usize> for Matrix<K> {
+    type Output = [K];
+
+    fn index(&self, i: usize) -> &[K] {
+        &self.data[i * self.n..(i + 1) * self.n]
+    }
+}
+
+impl<K> IndexMut<usize> for Matrix<K> {
+    fn index_mut(&mut self, i: usize) -> &mut [K] {
+        &mut self.data[i * self.n..(i + 1) * self.n]
+    }
+}
+
+impl<K> Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        assert!(j < self.n, "column index out of bounds");
+        &self.data[i * self.n + j]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for Matrix<K> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        assert!(j < self.n, "column index out of bounds");
+        &mut self.data[i * self.n + j]
+    }
+}
+
+impl<K: Scalar> AddAssign<&Matrix<K>> for Matrix<K> {
+    fn add_assign(&mut self, rhs: &Self) {
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n));
+        let isa = Isa::detect();
+        parallel::elementwise(&mut self.data, &rhs.data, |y, x| {
+            K::add_elementwise(isa, y, x)
+        });
+    }
+}
+
+impl<K: Scalar> SubAssign<&Matrix<K>> for Matrix<K> {
+    fn sub_assign(&mut self, rhs: &Self) {
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n));
+        let isa = Isa::detect();
+        parallel::elementwise(&mut self.data, &rhs.data, |y, x| {
+            K::sub_elementwise(isa, y, x)
+        });
+    }
+}
+
+operators!(Matrix, Add);
+operators!(Matrix, Sub);
+
+impl<K: Scalar> AddAssign<MatrixView<'_, K>> for Matrix<K> {
+    fn add_assign(&mut self, rhs: MatrixView<'_, K>) {
+        self.as_view_mut().add_assign(rhs);
     }
 }
 
-arithmetic!(Matrix, Add);
-arithmetic!(Matrix, Sub);
+impl<K: Scalar> SubAssign<MatrixView<'_, K>> for Matrix<K> {
+    fn sub_assign(&mut self, rhs: MatrixView<'_, K>) {
+        self.as_view_mut().sub_assign(rhs);
+    }
+}
 
-impl Mul<f32> for Matrix<f32> {
+impl<K: Scalar> Mul<K> for Matrix<K> {
     type Output = Self;
 
-    fn mul(self, f: f32) -> Self {
+    fn mul(self, f: K) -> Self {
         let mut res = self;
         res *= f;
         res
     }
 }
 
-impl<K: Scalar<K>> MulAssign<f32> for Matrix<K>
-where
-    Vector<K>: MulAssign<f32>,
-{
-    fn mul_assign(&mut self, rhs: f32) {
-        self.iter_mut().for_each(|u| {
-            *u *= rhs;
-        });
+impl<K: Scalar> Mul<K> for &Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn mul(self, f: K) -> Matrix<K> {
+        self.clone() * f
+    }
+}
+
+impl<K: Scalar> MulAssign<K> for Matrix<K> {
+    fn mul_assign(&mut self, rhs: K) {
+        parallel::apply(&mut self.data, |u| *u *= rhs);
+    }
+}
+
+/// The product of two matrices, see [`Matrix::mul_mat`].
+impl<K: Scalar> Mul<&Matrix<K>> for &Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn mul(self, rhs: &Matrix<K>) -> Matrix<K> {
+        self.mul_mat(rhs)
+    }
+}
+
+impl<K: Scalar> Mul<Matrix<K>> for &Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn mul(self, rhs: Matrix<K>) -> Matrix<K> {
+        self * &rhs
+    }
+}
+
+impl<K: Scalar> Mul<&Matrix<K>> for Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn mul(self, rhs: &Matrix<K>) -> Matrix<K> {
+        &self * rhs
+    }
+}
+
+impl<K: Scalar> Mul for Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn mul(self, rhs: Matrix<K>) -> Matrix<K> {
+        &self * &rhs
+    }
+}
+
+/// The product of a matrix and a vector, see [`Matrix::mul_vec`].
+impl<K: Scalar> Mul<&Vector<K>> for &Matrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Vector<K> {
+        self.mul_vec(rhs)
+    }
+}
+
+impl<K: Scalar> Mul<Vector<K>> for &Matrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: Vector<K>) -> Vector<K> {
+        self * &rhs
+    }
+}
+
+impl<K: Scalar> Mul<&Vector<K>> for Matrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: &Vector<K>) -> Vector<K> {
+        &self * rhs
+    }
+}
+
+impl<K: Scalar> Mul<Vector<K>> for Matrix<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, rhs: Vector<K>) -> Vector<K> {
+        &self * &rhs
+    }
+}
+
+impl<K: Scalar> Neg for Matrix<K> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        parallel::apply(&mut self.data, |u| *u = -*u);
+        self
+    }
+}
+
+impl<K: Scalar> Neg for &Matrix<K> {
+    type Output = Matrix<K>;
+
+    fn neg(self) -> Matrix<K> {
+        -self.clone()
     }
 }
 
-impl<K: Scalar<K>> From<&[Vector<K>]> for Matrix<K> {
+impl<K: Scalar> From<&[Vector<K>]> for Matrix<K> {
     fn from(value: &[Vector<K>]) -> Self {
-        value.into_iter().map(Clone::clone).collect_vec().into()
+        value.iter().cloned().collect()
     }
 }
 
-impl<K: Scalar<K>, const N: usize, const M: usize> From<[[K; M]; N]> for Matrix<K> {
+impl<K: Scalar, const N: usize, const M: usize> From<[[K; M]; N]> for Matrix<K> {
     fn from(value: [[K; M]; N]) -> Self {
-        value.into_iter().map(Into::into).collect_vec().into()
+        Matrix {
+            data: value.into_iter().flatten().collect(),
+            n: M,
+            m: if M == 0 { 0 } else { N },
+        }
     }
 }
 
-impl<K: Scalar<K>> From<Vec<Vector<K>>> for Matrix<K> {
+/// Builds a [`Matrix`] from its rows. Panics if the rows do not all have the same length.
+impl<K: Scalar> From<Vec<Vector<K>>> for Matrix<K> {
     fn from(value: Vec<Vector<K>>) -> Self {
-        let matrix: Vec<Vector<K>> = value.into_iter().collect_vec();
-        Matrix {
-            n: matrix[0].vector.len(),
-            m: if matrix[0].vector.is_empty() {
-                0
-            } else {
-                matrix.len()
-            },
-            matrix,
-        }
+        value.into_iter().collect()
     }
 }
 
-impl<K: Scalar<K>> FromIterator<Vector<K>> for Matrix<K> {
+impl<K: Scalar> FromIterator<Vector<K>> for Matrix<K> {
     fn from_iter<T: IntoIterator<Item = Vector<K>>>(iter: T) -> Self {
-        iter.into_iter().collect::<Vec<Vector<K>>>().into()
+        let mut data = Vec::new();
+        let (mut m, mut n) = (0, None);
+        for row in iter {
+            assert_eq!(
+                *n.get_or_insert(row.len()),
+                row.len(),
+                "rows of a matrix must have the same length"
+            );
+            data.extend(row.vector);
+            m += 1;
+        }
+        let n = n.unwrap_or(0);
+        Matrix {
+            data,
+            n,
+            m: if n == 0 { 0 } else { m },
+        }
     }
 }
 
-impl<K: Scalar<K> + std::fmt::Debug> fmt::Display for Matrix<K> {
+impl<K: Scalar + std::fmt::Debug> fmt::Display for Matrix<K> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if self.n == 0 {
             write!(f, "[]")
         } else {
-            self.iter()
-                .fold(Ok(()), |result, v| result.and_then(|_| writeln!(f, "{v}")))
+            self.row_iter().try_for_each(|v| writeln!(f, "{v:?}"))
         }
     }
 }
@@ -0,0 +1,122 @@
+use crate::{sparse::Csc, Scalar};
+
+/// Symmetric permutation applied to a sparse matrix before factorizing it, to reduce the fill-in
+/// of the factors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Ordering {
+    /// Keeps the rows and columns in their original order.
+    Natural,
+    /// Reverse Cuthill–McKee, which numbers the nodes of the graph of `A + Aᵀ` breadth first from
+    /// a pseudo-peripheral node to reduce the bandwidth, and hence the profile of the factors.
+    #[default]
+    ReverseCuthillMcKee,
+}
+
+impl Ordering {
+    /// Computes the permutation for the pattern of the given square [`Csc`] matrix: row and
+    /// column `k` of the permuted matrix are row and column `permutation[k]` of `a`.
+    /// ```
+    /// # use matrix::{matrix::Matrix, ordering::Ordering, sparse::Csc};
+    /// let a = Csc::from(&Matrix::from([[1., 0., 1.], [0., 1., 0.], [1., 0., 1.]]));
+    /// assert_eq!(Ordering::Natural.permutation(&a), [0, 1, 2]);
+    /// assert_eq!(Ordering::ReverseCuthillMcKee.permutation(&a), [2, 0, 1]);
+    /// ```
+    pub fn permutation<K: Scalar>(&self, a: &Csc<K>) -> Vec<usize> {
+        let n = a.columns();
+        match self {
+            Ordering::Natural => (0..n).collect(),
+            Ordering::ReverseCuthillMcKee => reverse_cuthill_mckee(&adjacency(a)),
+        }
+    }
+}
+
+/// Returns the neighbours of every node in the graph of `A + Aᵀ`, without self-loops.
+fn adjacency<K: Scalar>(a: &Csc<K>) -> Vec<Vec<usize>> {
+    let mut adjacency = vec![Vec::new(); a.columns()];
+    for j in 0..a.columns() {
+        for &i in a.col(j).0.iter().filter(|&&i| i != j) {
+            adjacency[i].push(j);
+            adjacency[j].push(i);
+        }
+    }
+    adjacency.iter_mut().for_each(|neighbours| {
+        neighbours.sort_unstable();
+        neighbours.dedup();
+    });
+    adjacency
+}
+
+fn reverse_cuthill_mckee(adjacency: &[Vec<usize>]) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    // Every pass numbers one connected component.
+    while let Some(start) = (0..n)
+        .filter(|&i| !visited[i])
+        .min_by_key(|&i| adjacency[i].len())
+    {
+        let root = pseudo_peripheral(adjacency, start);
+        visited[root] = true;
+        order.push(root);
+        let mut head = order.len() - 1;
+        while head < order.len() {
+            let mut neighbours: Vec<usize> = adjacency[order[head]]
+                .iter()
+                .copied()
+                .filter(|&j| !visited[j])
+                .collect();
+            neighbours.sort_by_key(|&j| adjacency[j].len());
+            for j in neighbours {
+                visited[j] = true;
+                order.push(j);
+            }
+            head += 1;
+        }
+    }
+    order.reverse();
+    order
+}
+
+/// Finds a node of (nearly) maximal eccentricity in the component of `start`, following George
+/// and Liu: restart the breadth-first search from a node of minimum degree in the last level for
+/// as long as the number of levels grows.
+fn pseudo_peripheral(adjacency: &[Vec<usize>], start: usize) -> usize {
+    let mut root = start;
+    let mut levels = level_structure(adjacency, root);
+    loop {
+        let candidate = *levels
+            .last()
+            .unwrap()
+            .iter()
+            .min_by_key(|&&j| adjacency[j].len())
+            .unwrap();
+        let candidate_levels = level_structure(adjacency, candidate);
+        if candidate_levels.len() <= levels.len() {
+            return root;
+        }
+        root = candidate;
+        levels = candidate_levels;
+    }
+}
+
+/// Groups the nodes reachable from `root` by their distance to it.
+fn level_structure(adjacency: &[Vec<usize>], root: usize) -> Vec<Vec<usize>> {
+    let mut visited = vec![false; adjacency.len()];
+    visited[root] = true;
+    let mut levels = vec![vec![root]];
+    loop {
+        let mut next = Vec::new();
+        for &i in levels.last().unwrap() {
+            for &j in &adjacency[i] {
+                if !visited[j] {
+                    visited[j] = true;
+                    next.push(j);
+                }
+            }
+        }
+        if next.is_empty() {
+            return levels;
+        }
+        levels.push(next);
+    }
+}
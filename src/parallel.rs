@@ -0,0 +1,146 @@
+#[cfg(feature = "parallel")]
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+#[cfg(feature = "parallel")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+/// Default of [`threshold`]: operations with fewer multiply-adds than this run on the calling
+/// thread, because splitting them costs more than it saves.
+#[cfg(feature = "parallel")]
+pub const DEFAULT_THRESHOLD: usize = 1 << 16;
+
+/// Number of elements that one task of an element-wise operation handles.
+const ELEMENTWISE_CHUNK: usize = 1 << 12;
+
+#[cfg(feature = "parallel")]
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "parallel")]
+static THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_THRESHOLD);
+
+#[cfg(feature = "parallel")]
+static POOL: Mutex<Option<Arc<ThreadPool>>> = Mutex::new(None);
+
+/// Sets the number of threads that matrix operations are split across. `0` restores the default,
+/// which is one thread per logical CPU, and `1` runs every operation on the calling thread.
+/// Operations that are already running keep their threads.
+/// ```
+/// # use matrix::parallel;
+/// parallel::set_num_threads(2);
+/// assert_eq!(parallel::num_threads(), 2);
+/// parallel::set_num_threads(0);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn set_num_threads(threads: usize) {
+    let mut pool = POOL.lock().unwrap();
+    THREADS.store(threads, Ordering::Relaxed);
+    *pool = None;
+}
+
+/// Returns the number of threads that matrix operations are split across.
+#[cfg(feature = "parallel")]
+pub fn num_threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        threads => threads,
+    }
+}
+
+/// Sets the number of multiply-adds, or of elements for element-wise operations, below which an
+/// operation runs on the calling thread.
+#[cfg(feature = "parallel")]
+pub fn set_threshold(work: usize) {
+    THRESHOLD.store(work, Ordering::Relaxed);
+}
+
+/// Returns the size below which operations run on the calling thread, [`DEFAULT_THRESHOLD`]
+/// unless it was changed with [`set_threshold`].
+#[cfg(feature = "parallel")]
+pub fn threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Returns the thread pool for the configured number of threads, building it on first use, or
+/// `None` if operations should stay on the calling thread.
+#[cfg(feature = "parallel")]
+fn pool() -> Option<Arc<ThreadPool>> {
+    let threads = num_threads();
+    if threads <= 1 {
+        return None;
+    }
+    let mut pool = POOL.lock().unwrap();
+    if pool.is_none() {
+        *pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .ok()
+            .map(Arc::new);
+    }
+    pool.clone()
+}
+
+/// Calls `f` with the index and contents of every chunk of `chunk` elements of `data`, which
+/// holds `work` multiply-adds in total. With the `parallel` feature the chunks are spread over
+/// the thread pool once `work` reaches the [`threshold`]; `init` then creates the scratch state
+/// of every task, instead of the single state of the serial loop.
+pub(crate) fn for_each_chunk_init<K: Send, T>(
+    data: &mut [K],
+    chunk: usize,
+    work: usize,
+    init: impl Fn() -> T + Sync + Send,
+    f: impl Fn(&mut T, usize, &mut [K]) + Sync + Send,
+) {
+    let chunk = chunk.max(1);
+    #[cfg(feature = "parallel")]
+    if work >= threshold() && data.len() > chunk {
+        if let Some(pool) = pool() {
+            pool.install(|| {
+                data.par_chunks_mut(chunk)
+                    .enumerate()
+                    .for_each_init(&init, |state, (i, c)| f(state, i, c))
+            });
+            return;
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = work;
+    let mut state = init();
+    data.chunks_mut(chunk)
+        .enumerate()
+        .for_each(|(i, c)| f(&mut state, i, c));
+}
+
+/// [`for_each_chunk_init`] without scratch state.
+pub(crate) fn for_each_chunk<K: Send>(
+    data: &mut [K],
+    chunk: usize,
+    work: usize,
+    f: impl Fn(usize, &mut [K]) + Sync + Send,
+) {
+    for_each_chunk_init(data, chunk, work, || (), |_, i, c| f(i, c));
+}
+
+/// Applies the element-wise kernel `f` to matching chunks of `y` and `x`. Panics if their lengths
+/// differ.
+pub(crate) fn elementwise<K: Send + Sync>(
+    y: &mut [K],
+    x: &[K],
+    f: impl Fn(&mut [K], &[K]) + Sync + Send,
+) {
+    assert_eq!(y.len(), x.len(), "lengths of the operands must agree");
+    let len = y.len();
+    for_each_chunk(y, ELEMENTWISE_CHUNK, len, |i, y| {
+        let start = i * ELEMENTWISE_CHUNK;
+        f(y, &x[start..start + y.len()])
+    });
+}
+
+/// Applies `f` to every element of `data`, in chunks like [`elementwise`].
+pub(crate) fn apply<K: Send>(data: &mut [K], f: impl Fn(&mut K) + Sync + Send) {
+    let len = data.len();
+    for_each_chunk(data, ELEMENTWISE_CHUNK, len, |_, chunk| {
+        chunk.iter_mut().for_each(&f)
+    });
+}
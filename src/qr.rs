@@ -0,0 +1,179 @@
+use crate::{
+    error::Error, matrix::Matrix, parallel, vector::Vector, view::MatrixView, RealScalar, Scalar,
+};
+use anyhow::Result;
+use num::{One, Zero};
+
+/// QR decomposition `A = QR` of a [`Matrix`] of any shape `m`x`n`. `Q` is a unitary `m`x`m`
+/// [`Matrix`], which is orthogonal for real matrices, and `R` is an upper triangular `m`x`n`
+/// [`Matrix`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Qr<K> {
+    q: Matrix<K>,
+    r: Matrix<K>,
+}
+
+impl<K: Scalar> Qr<K> {
+    /// Factorizes the given [`Matrix`] using Householder reflections.
+    pub fn householder<'a>(a: impl Into<MatrixView<'a, K>>) -> Qr<K>
+    where
+        K: 'a,
+    {
+        let mut r = a.into().to_matrix();
+        let m = r.m;
+        let mut q = Matrix::identity(m);
+        triangularize(&mut r, |k, v, scale| {
+            parallel::for_each_chunk(q.as_mut_slice(), m, m * (m - k), |_, row| {
+                let s = (0..v.len()).fold(K::zero(), |acc, l| acc + row[k + l] * v[l]) * scale;
+                (0..v.len()).for_each(|l| row[k + l] -= s * v[l].conj());
+            });
+        });
+        Qr { q, r }
+    }
+
+    /// Factorizes the given [`Matrix`] using Givens rotations.
+    pub fn givens<'a>(a: impl Into<MatrixView<'a, K>>) -> Qr<K>
+    where
+        K: 'a,
+    {
+        let r = a.into().to_matrix();
+        let (m, n) = (r.m, r.n);
+        let mut qr = Qr {
+            q: Matrix::identity(m),
+            r,
+        };
+        for j in 0..n {
+            ((j + 1)..m).rev().for_each(|i| qr.rotate(j, i, j));
+        }
+        qr
+    }
+
+    /// Applies the Givens rotation in the plane of rows `j` and `i` of `R` that zeroes `R[i][col]`,
+    /// and accumulates its conjugate transpose into `Q`.
+    fn rotate(&mut self, j: usize, i: usize, col: usize) {
+        let (a, b) = (self.r[j][col], self.r[i][col]);
+        if b == K::zero() {
+            return;
+        }
+        let h = K::from_real(a.modulus().hypot(b.modulus()));
+        let (c, s) = (a / h, b / h);
+
+        for k in 0..self.r.n {
+            let (x, y) = (self.r[j][k], self.r[i][k]);
+            self.r[j][k] = c.conj() * x + s.conj() * y;
+            self.r[i][k] = c * y - s * x;
+        }
+        self.r[i][col] = K::zero();
+        for k in 0..self.q.m {
+            let (x, y) = (self.q[k][j], self.q[k][i]);
+            self.q[k][j] = c * x + s * y;
+            self.q[k][i] = c.conj() * y - s.conj() * x;
+        }
+    }
+
+    /// Updates the factorization in place for the [`Matrix`] with `row` appended at the bottom,
+    /// using Givens rotations instead of refactorizing from scratch.
+    /// ```
+    /// # use matrix::{matrix::Matrix, vector::Vector};
+    /// let mut qr = Matrix::<f32>::from([[3., 0.], [4., 5.]]).qr();
+    /// qr.append_row(&Vector::from([0., 4.])).unwrap();
+    /// assert_eq!(qr.r().shape(), (2, 3));
+    /// assert!((qr.r()[1][1].abs() - 5.).abs() < 1e-6);
+    /// ```
+    pub fn append_row(&mut self, row: &Vector<K>) -> Result<()> {
+        if row.len() != self.r.n {
+            return Err(Error::DimensionMismatch {
+                expected: self.r.n,
+                found: row.len(),
+            }
+            .into());
+        }
+        let m = self.q.m;
+        let mut q = Matrix::zeros(m + 1, m + 1);
+        (0..m).for_each(|i| q[i][..m].copy_from_slice(&self.q[i]));
+        q[m][m] = K::one();
+        self.q = q;
+        self.r = Matrix::from_vec(
+            m + 1,
+            self.r.n,
+            self.r
+                .as_slice()
+                .iter()
+                .chain(row.iter())
+                .copied()
+                .collect(),
+        )?;
+
+        (0..self.r.n.min(m)).for_each(|j| self.rotate(j, m, j));
+        Ok(())
+    }
+
+    /// Returns the unitary factor `Q`.
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    /// Returns the upper triangular factor `R`.
+    pub fn r(&self) -> &Matrix<K> {
+        &self.r
+    }
+}
+
+/// Reduces `A` to `R` with the reflections of [`Qr::householder`], applying them to `b` instead of
+/// accumulating `Q`, which turns `b` into `Qᴴb` without ever forming the `m`x`m` factor.
+pub(crate) fn householder_r<'a, K: Scalar + 'a>(
+    a: impl Into<MatrixView<'a, K>>,
+    b: &mut [K],
+) -> Matrix<K> {
+    let mut r = a.into().to_matrix();
+    assert_eq!(b.len(), r.m, "lengths of the operands must agree");
+    triangularize(&mut r, |k, v, scale| {
+        let s = (0..v.len()).fold(K::zero(), |acc, l| acc + v[l].conj() * b[k + l]) * scale;
+        (0..v.len()).for_each(|l| b[k + l] -= s * v[l]);
+    });
+    r
+}
+
+/// Reduces `r` to upper triangular form with Householder reflections `I - scale vvᴴ`, and calls
+/// `reflect` with the column `k`, `v` and `scale` of each one, which acts on the rows from `k` on.
+fn triangularize<K: Scalar>(r: &mut Matrix<K>, mut reflect: impl FnMut(usize, &[K], K)) {
+    let (m, n) = (r.m, r.n);
+    for k in 0..n.min(m.saturating_sub(1)) {
+        let mut v: Vec<K> = (k..m).map(|i| r[i][k]).collect();
+        let norm = squared_norm(&v).sqrt();
+        if norm == K::Real::zero() {
+            continue;
+        }
+        let alpha = -sign(v[0]) * K::from_real(norm);
+        v[0] -= alpha;
+        let scale = K::from_real((K::Real::one() + K::Real::one()) / squared_norm(&v));
+
+        let work = (m - k) * (n - k);
+        let mut s = vec![K::zero(); n - k];
+        parallel::for_each_chunk(&mut s, 1, work, |j, s| {
+            s[0] =
+                (0..v.len()).fold(K::zero(), |acc, l| acc + v[l].conj() * r[k + l][k + j]) * scale;
+        });
+        parallel::for_each_chunk(&mut r.as_mut_slice()[k * n..], n, work, |l, row| {
+            (k..n).for_each(|j| row[j] -= s[j - k] * v[l]);
+        });
+        reflect(k, &v, scale);
+        r[k][k] = alpha;
+        ((k + 1)..m).for_each(|i| r[i][k] = K::zero());
+    }
+}
+
+/// Sum of the squared moduli of the elements of `v`.
+fn squared_norm<K: Scalar>(v: &[K]) -> K::Real {
+    v.iter().map(|x| x.modulus_sqr()).sum()
+}
+
+/// Unit-modulus factor `x / |x|`, or one if `x` is zero. For real scalars this is the sign.
+fn sign<K: Scalar>(x: K) -> K {
+    let modulus = x.modulus();
+    if modulus == K::Real::zero() {
+        K::one()
+    } else {
+        x / K::from_real(modulus)
+    }
+}
@@ -0,0 +1,333 @@
+use crate::{error::Error, matrix::Matrix, view::MatrixView, RealScalar, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, Complex, NumCast, One, Zero};
+
+/// Maximum number of Francis QR steps spent on deflating a single eigenvalue or 2x2 block.
+const MAX_ITERATIONS: usize = 100;
+
+/// Hessenberg decomposition `A = QHQᴴ` of a square [`Matrix`], where `Q` is unitary (orthogonal for
+/// real matrices) and `H` is zero below its first subdiagonal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hessenberg<K> {
+    q: Matrix<K>,
+    h: Matrix<K>,
+}
+
+impl<K: Scalar> Hessenberg<K> {
+    /// Reduces the given square [`Matrix`] to upper Hessenberg form using Householder reflections.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>) -> Result<Hessenberg<K>>
+    where
+        K: 'a,
+    {
+        let mut h = a.into().to_matrix();
+        if h.m != h.n {
+            return Err(Error::NotSquare {
+                rows: h.m,
+                columns: h.n,
+            }
+            .into());
+        }
+        let n = h.n;
+        let mut q = Matrix::identity(n);
+
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<K> = ((k + 1)..n).map(|i| h[i][k]).collect();
+            if let Some((v, beta)) = householder(&x) {
+                reflect_rows(&mut h, &v, beta, k + 1, k..n);
+                reflect_columns(&mut h, &v, beta, k + 1, 0..n);
+                reflect_columns(&mut q, &v, beta, k + 1, 0..n);
+                ((k + 2)..n).for_each(|i| h[i][k] = K::zero());
+            }
+        }
+        Ok(Hessenberg { q, h })
+    }
+
+    /// Returns the unitary factor `Q`.
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    /// Returns the upper Hessenberg factor `H`.
+    pub fn h(&self) -> &Matrix<K> {
+        &self.h
+    }
+}
+
+/// Schur decomposition `A = ZTZᴴ` of a square [`Matrix`], with unitary Schur vectors `Z` that are
+/// only computed on request. For complex matrices `T` is upper triangular. Real matrices get their
+/// real Schur form instead, with an orthogonal `Z` and a quasi upper triangular `T`: its diagonal
+/// holds 1x1 blocks for real eigenvalues and 2x2 blocks for complex conjugate pairs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schur<K> {
+    t: Matrix<K>,
+    z: Option<Matrix<K>>,
+}
+
+impl<K: Scalar> Schur<K> {
+    /// Computes the Schur form of the given square [`Matrix`] by Hessenberg reduction followed by
+    /// the Francis double-shift QR iteration. Returns an error if an eigenvalue does not converge.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>, compute_vectors: bool) -> Result<Schur<K>>
+    where
+        K: 'a,
+    {
+        let Hessenberg { q, h } = Hessenberg::new(a)?;
+        let mut schur = Schur {
+            t: h,
+            z: compute_vectors.then_some(q),
+        };
+        schur.francis_qr()?;
+        Ok(schur)
+    }
+
+    /// Returns the (quasi) upper triangular factor `T`.
+    pub fn t(&self) -> &Matrix<K> {
+        &self.t
+    }
+
+    /// Returns the unitary Schur vectors `Z` as columns, if they were computed.
+    pub fn z(&self) -> Option<&Matrix<K>> {
+        self.z.as_ref()
+    }
+
+    /// Returns the eigenvalues in the order of the diagonal blocks of `T`. For real matrices complex
+    /// conjugate pairs are adjacent, with the positive imaginary part first.
+    pub fn eigenvalues(&self) -> Vec<Complex<K::Real>> {
+        let (t, n) = (&self.t, self.t.n);
+        let mut eigenvalues = Vec::with_capacity(n);
+        let mut i = 0;
+        while i < n {
+            if i + 1 < n && t[i + 1][i] != K::zero() {
+                let (re, im) = block_eigenvalues(
+                    t[i][i].re(),
+                    t[i][i + 1].re(),
+                    t[i + 1][i].re(),
+                    t[i + 1][i + 1].re(),
+                );
+                eigenvalues.push(Complex::new(re, im));
+                eigenvalues.push(Complex::new(re, -im));
+                i += 2;
+            } else {
+                eigenvalues.push(Complex::new(t[i][i].re(), t[i][i].im()));
+                i += 1;
+            }
+        }
+        eigenvalues
+    }
+
+    /// Runs the Francis double-shift QR iteration on the Hessenberg matrix `T` until it is in Schur
+    /// form, accumulating the transformations into `Z`.
+    fn francis_qr(&mut self) -> Result<()> {
+        let n = self.t.n;
+        let norm = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .fold(K::Real::zero(), |acc, (i, j)| acc + self.t[i][j].modulus());
+        let mut hi = n;
+        let mut iterations = 0;
+
+        while hi > 0 {
+            let h = &mut self.t;
+            let mut l = hi - 1;
+            while l > 0 {
+                let mut scale = h[l - 1][l - 1].modulus() + h[l][l].modulus();
+                if scale == K::Real::zero() {
+                    scale = norm;
+                }
+                if h[l][l - 1].modulus() <= K::Real::epsilon() * scale {
+                    h[l][l - 1] = K::zero();
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == hi - 1 {
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            if l == hi - 2 {
+                self.standardize_block(l);
+                hi -= 2;
+                iterations = 0;
+                continue;
+            }
+            if iterations == MAX_ITERATIONS {
+                return Err(Error::NoConvergence {
+                    iterations: MAX_ITERATIONS,
+                }
+                .into());
+            }
+            iterations += 1;
+            self.francis_step(l, hi - 1, iterations);
+        }
+        Ok(())
+    }
+
+    /// Performs one implicit double-shift QR step on the active window `l..=hi` of `T`.
+    fn francis_step(&mut self, l: usize, hi: usize, iterations: usize) {
+        let n = self.t.n;
+        let h = &self.t;
+        let (s, t) = if iterations.is_multiple_of(10) {
+            let w = h[hi][hi - 1].modulus() + h[hi - 1][hi - 2].modulus();
+            let factor = <K::Real as NumCast>::from(1.5).unwrap();
+            (K::from_real(w * factor), K::from_real(w * w))
+        } else {
+            (
+                h[hi - 1][hi - 1] + h[hi][hi],
+                h[hi - 1][hi - 1] * h[hi][hi] - h[hi - 1][hi] * h[hi][hi - 1],
+            )
+        };
+        let mut x = h[l][l] * h[l][l] + h[l][l + 1] * h[l + 1][l] - s * h[l][l] + t;
+        let mut y = h[l + 1][l] * (h[l][l] + h[l + 1][l + 1] - s);
+        let mut z = h[l + 1][l] * h[l + 2][l + 1];
+
+        for k in l..(hi - 1) {
+            if let Some((v, beta)) = householder(&[x, y, z]) {
+                let first = if k > l { k - 1 } else { l };
+                reflect_rows(&mut self.t, &v, beta, k, first..n);
+                reflect_columns(&mut self.t, &v, beta, k, 0..(k + 4).min(hi + 1));
+                if let Some(z) = self.z.as_mut() {
+                    reflect_columns(z, &v, beta, k, 0..n);
+                }
+                if k > l {
+                    self.t[k + 1][k - 1] = K::zero();
+                    self.t[k + 2][k - 1] = K::zero();
+                }
+            }
+            x = self.t[k + 1][k];
+            y = self.t[k + 2][k];
+            if k + 3 <= hi {
+                z = self.t[k + 3][k];
+            }
+        }
+        if let Some((v, beta)) = householder(&[x, y]) {
+            reflect_rows(&mut self.t, &v, beta, hi - 1, (hi - 2)..n);
+            reflect_columns(&mut self.t, &v, beta, hi - 1, 0..(hi + 1));
+            if let Some(z) = self.z.as_mut() {
+                reflect_columns(z, &v, beta, hi - 1, 0..n);
+            }
+            self.t[hi][hi - 2] = K::zero();
+        }
+    }
+
+    /// Splits the deflated 2x2 block at `p` into two 1x1 blocks with a rotation if its eigenvalues
+    /// lie in the field of `K`, so that only complex conjugate pairs of real matrices remain as 2x2
+    /// blocks in `T`.
+    fn standardize_block(&mut self, p: usize) {
+        let (n, q) = (self.t.n, p + 1);
+        let (a, b, c, d) = (self.t[p][p], self.t[p][q], self.t[q][p], self.t[q][q]);
+        let half = K::from_real(<K::Real as NumCast>::from(0.5).unwrap());
+        let discriminant = (a - d) * (a - d) * half * half + b * c;
+        // The square root is NaN exactly if the eigenvalues of a real block are complex.
+        let root = discriminant.sqrt();
+        if root.re().is_nan() || c == K::zero() {
+            return;
+        }
+        let mean = (a + d) * half;
+        let lambda = if (mean + root).modulus() >= (mean - root).modulus() {
+            mean + root
+        } else {
+            mean - root
+        };
+        let (x, y) = if (lambda - d).modulus() + c.modulus() >= b.modulus() + (lambda - a).modulus()
+        {
+            (lambda - d, c)
+        } else {
+            (b, lambda - a)
+        };
+        let r = x.modulus().hypot(y.modulus());
+        if r == K::Real::zero() {
+            return;
+        }
+        let (cs, sn) = (x / K::from_real(r), y / K::from_real(r));
+
+        for j in p..n {
+            let (u, v) = (self.t[p][j], self.t[q][j]);
+            self.t[p][j] = cs.conj() * u + sn.conj() * v;
+            self.t[q][j] = cs * v - sn * u;
+        }
+        let mut matrices = vec![&mut self.t];
+        if let Some(z) = self.z.as_mut() {
+            matrices.push(z);
+        }
+        for w in matrices {
+            for i in 0..w.m {
+                let (u, v) = (w[i][p], w[i][q]);
+                w[i][p] = cs * u + sn * v;
+                w[i][q] = cs.conj() * v - sn.conj() * u;
+            }
+        }
+        self.t[q][p] = K::zero();
+    }
+}
+
+/// Eigenvalues of the 2x2 block `[[a, b], [c, d]]` with complex eigenvalues, returned as the real
+/// part and the positive imaginary part. Falls back to a zero imaginary part if they are real.
+fn block_eigenvalues<K: RealScalar>(a: K, b: K, c: K, d: K) -> (K, K) {
+    let half = <K as NumCast>::from(0.5).unwrap();
+    let discriminant = (a - d) * (a - d) * half * half + b * c;
+    let mean = (a + d) * half;
+    if discriminant < K::zero() {
+        (mean, (-discriminant).sqrt())
+    } else {
+        (mean, K::zero())
+    }
+}
+
+/// Computes the Householder reflector `P = I - βvvᴴ` that maps `x` onto a multiple of the first
+/// unit vector, or `None` if `x` is zero.
+fn householder<K: Scalar>(x: &[K]) -> Option<(Vec<K>, K)> {
+    let squared_norm = |v: &[K]| v.iter().map(|x| x.modulus_sqr()).sum::<K::Real>();
+    let norm = squared_norm(x).sqrt();
+    if norm == K::Real::zero() {
+        return None;
+    }
+    let mut v = x.to_vec();
+    let modulus = x[0].modulus();
+    v[0] += if modulus == K::Real::zero() {
+        K::from_real(norm)
+    } else {
+        x[0] * K::from_real(norm / modulus)
+    };
+    let beta = K::from_real((K::Real::one() + K::Real::one()) / squared_norm(&v));
+    Some((v, beta))
+}
+
+/// Applies the reflector to rows `start..start + v.len()` of `a`, restricted to `columns`.
+fn reflect_rows<K: Scalar>(
+    a: &mut Matrix<K>,
+    v: &[K],
+    beta: K,
+    start: usize,
+    columns: std::ops::Range<usize>,
+) {
+    for j in columns {
+        let s = v
+            .iter()
+            .enumerate()
+            .fold(K::zero(), |acc, (l, v)| acc + v.conj() * a[start + l][j])
+            * beta;
+        v.iter()
+            .enumerate()
+            .for_each(|(l, v)| a[start + l][j] -= s * *v);
+    }
+}
+
+/// Applies the reflector to columns `start..start + v.len()` of `a`, restricted to `rows`.
+fn reflect_columns<K: Scalar>(
+    a: &mut Matrix<K>,
+    v: &[K],
+    beta: K,
+    start: usize,
+    rows: std::ops::Range<usize>,
+) {
+    for i in rows {
+        let s = v
+            .iter()
+            .enumerate()
+            .fold(K::zero(), |acc, (l, v)| acc + *v * a[i][start + l])
+            * beta;
+        v.iter()
+            .enumerate()
+            .for_each(|(l, v)| a[i][start + l] -= s * v.conj());
+    }
+}
@@ -0,0 +1,382 @@
+use num::{traits::NumAssign, Complex, Num};
+use std::{ops::Neg, sync::OnceLock};
+
+/// Number of rows of `A` and `C` that [`Kernels::gemm_kernel`] processes at once.
+pub const MR: usize = 4;
+
+/// Number of columns of `B` and `C` that [`Kernels::gemm_kernel`] processes at once.
+pub const NR: usize = 8;
+
+/// Instruction sets the [`Kernels`] can run on, ordered by register width.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Isa {
+    /// Plain loops, which work on every target and for every scalar.
+    Scalar,
+    /// 128-bit SSE and SSE2 registers, which every `x86_64` build can use.
+    Sse2,
+    /// 256-bit AVX registers.
+    Avx,
+}
+
+impl Isa {
+    /// Returns the widest instruction set that the running CPU supports, which the SIMD kernels
+    /// are compiled for regardless of the target features of the build. The result is detected
+    /// once and then cached.
+    pub fn detect() -> Isa {
+        static ISA: OnceLock<Isa> = OnceLock::new();
+        *ISA.get_or_init(|| {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if std::arch::is_x86_feature_detected!("avx") {
+                    return Isa::Avx;
+                }
+                if std::arch::is_x86_feature_detected!("sse2") {
+                    return Isa::Sse2;
+                }
+            }
+            Isa::Scalar
+        })
+    }
+}
+
+/// Dense kernels behind the arithmetic of [`Vector`](crate::vector::Vector) and
+/// [`Matrix`](crate::matrix::Matrix). `f32` and `f64` run them with SIMD instructions on the
+/// requested [`Isa`], lowered to [`Isa::detect`] if the CPU lacks it. Complex scalars
+/// and [`Isa::Scalar`] use plain loops, which the SIMD paths agree with up to rounding. Panics if
+/// the lengths of the operands differ.
+pub trait Kernels: Sized {
+    /// Computes `Σ xᵢ yᵢ`.
+    fn dot(isa: Isa, x: &[Self], y: &[Self]) -> Self;
+
+    /// Computes `Σ conj(xᵢ) yᵢ`, which is [`Kernels::dot`] for real scalars.
+    fn dotc(isa: Isa, x: &[Self], y: &[Self]) -> Self;
+
+    /// Computes `y += alpha * x`.
+    fn axpy(isa: Isa, alpha: Self, x: &[Self], y: &mut [Self]);
+
+    /// Computes `y += x` element-wise.
+    fn add_elementwise(isa: Isa, y: &mut [Self], x: &[Self]);
+
+    /// Computes `y -= x` element-wise.
+    fn sub_elementwise(isa: Isa, y: &mut [Self], x: &[Self]);
+
+    /// Computes `y *= x` element-wise.
+    fn mul_elementwise(isa: Isa, y: &mut [Self], x: &[Self]);
+
+    /// Micro-kernel of the matrix product, which adds `AB` to the [`MR`]x[`NR`] block at the start
+    /// of `c`, whose rows are `ldc` elements apart. `a` packs the `k` columns of `A` as [`MR`]
+    /// consecutive elements each, and `b` packs the `k` rows of `B` as [`NR`] consecutive elements
+    /// each.
+    fn gemm_kernel(isa: Isa, k: usize, a: &[Self], b: &[Self], c: &mut [Self], ldc: usize);
+}
+
+fn check_lengths(x: usize, y: usize) {
+    assert_eq!(x, y, "lengths of the operands must agree");
+}
+
+fn check_gemm(k: usize, a: usize, b: usize, c: usize, ldc: usize) {
+    assert!(a >= k * MR && b >= k * NR, "packed panels are too short");
+    assert!(
+        ldc >= NR && c >= (MR - 1) * ldc + NR,
+        "block of C is out of bounds"
+    );
+}
+
+/// The plain loops that every [`Isa`] falls back to.
+mod scalar {
+    use super::{MR, NR};
+    use num::traits::NumAssign;
+
+    pub fn dot<K: NumAssign + Copy>(x: &[K], y: &[K]) -> K {
+        x.iter().zip(y).fold(K::zero(), |acc, (x, y)| acc + *x * *y)
+    }
+
+    pub fn axpy<K: NumAssign + Copy>(alpha: K, x: &[K], y: &mut [K]) {
+        y.iter_mut().zip(x).for_each(|(y, x)| *y += alpha * *x);
+    }
+
+    pub fn add<K: NumAssign + Copy>(y: &mut [K], x: &[K]) {
+        y.iter_mut().zip(x).for_each(|(y, x)| *y += *x);
+    }
+
+    pub fn sub<K: NumAssign + Copy>(y: &mut [K], x: &[K]) {
+        y.iter_mut().zip(x).for_each(|(y, x)| *y -= *x);
+    }
+
+    pub fn mul<K: NumAssign + Copy>(y: &mut [K], x: &[K]) {
+        y.iter_mut().zip(x).for_each(|(y, x)| *y *= *x);
+    }
+
+    pub fn gemm_kernel<K: NumAssign + Copy>(k: usize, a: &[K], b: &[K], c: &mut [K], ldc: usize) {
+        for p in 0..k {
+            for i in 0..MR {
+                let a = a[p * MR + i];
+                for j in 0..NR {
+                    c[i * ldc + j] += a * b[p * NR + j];
+                }
+            }
+        }
+    }
+}
+
+/// Generates an element-wise kernel of a [`simd!`] module from the intrinsic `$op`.
+macro_rules! elementwise {
+    ($name:ident, $feature:literal, $t:ty, $lanes:literal, $op:ident, $tail:path) => {
+        #[target_feature(enable = $feature)]
+        pub unsafe fn $name(y: &mut [$t], x: &[$t]) {
+            let (mut xs, mut ys) = (x.chunks_exact($lanes), y.chunks_exact_mut($lanes));
+            for (x, y) in (&mut xs).zip(&mut ys) {
+                let v = $op(load(y), load(x));
+                store(y, v);
+            }
+            $tail(ys.into_remainder(), xs.remainder());
+        }
+    };
+}
+
+/// Generates the kernels of one register type, which holds `$lanes` elements of type `$t`. They
+/// are compiled with `$feature` enabled whatever the target features of the build, so they may
+/// only be called once [`Isa::detect`] has found that the CPU supports it.
+macro_rules! simd {
+    (
+        $module:ident, $feature:literal, $t:ty, $lanes:literal, $reg:ty,
+        $load:ident, $store:ident, $splat:ident, $zeroed:ident, $add:ident, $sub:ident, $mul:ident
+    ) => {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        mod $module {
+            use super::{scalar, MR, NR};
+            #[cfg(target_arch = "x86")]
+            use std::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use std::arch::x86_64::*;
+
+            const W: usize = NR / $lanes;
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn load(x: &[$t]) -> $reg {
+                let x: &[$t; $lanes] = x.try_into().unwrap();
+                $load(x.as_ptr())
+            }
+
+            #[inline]
+            #[target_feature(enable = $feature)]
+            unsafe fn store(y: &mut [$t], v: $reg) {
+                let y: &mut [$t; $lanes] = y.try_into().unwrap();
+                $store(y.as_mut_ptr(), v)
+            }
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn dot(x: &[$t], y: &[$t]) -> $t {
+                let (xs, ys) = (x.chunks_exact($lanes), y.chunks_exact($lanes));
+                let tail = scalar::dot(xs.remainder(), ys.remainder());
+                let mut acc = $zeroed();
+                for (x, y) in xs.zip(ys) {
+                    acc = $add(acc, $mul(load(x), load(y)));
+                }
+                let mut lanes = [0.; $lanes];
+                store(&mut lanes, acc);
+                lanes.iter().sum::<$t>() + tail
+            }
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn axpy(alpha: $t, x: &[$t], y: &mut [$t]) {
+                let a = $splat(alpha);
+                let (mut xs, mut ys) = (x.chunks_exact($lanes), y.chunks_exact_mut($lanes));
+                for (x, y) in (&mut xs).zip(&mut ys) {
+                    let v = $add(load(y), $mul(a, load(x)));
+                    store(y, v);
+                }
+                scalar::axpy(alpha, xs.remainder(), ys.into_remainder());
+            }
+
+            elementwise!(add, $feature, $t, $lanes, $add, scalar::add);
+            elementwise!(sub, $feature, $t, $lanes, $sub, scalar::sub);
+            elementwise!(mul, $feature, $t, $lanes, $mul, scalar::mul);
+
+            #[target_feature(enable = $feature)]
+            pub unsafe fn gemm_kernel(k: usize, a: &[$t], b: &[$t], c: &mut [$t], ldc: usize) {
+                let mut acc = [[$zeroed(); W]; MR];
+                for p in 0..k {
+                    let b = &b[p * NR..(p + 1) * NR];
+                    let mut panel = [$zeroed(); W];
+                    for (j, v) in panel.iter_mut().enumerate() {
+                        *v = load(&b[j * $lanes..(j + 1) * $lanes]);
+                    }
+                    for (i, row) in acc.iter_mut().enumerate() {
+                        let a = $splat(a[p * MR + i]);
+                        for (c, b) in row.iter_mut().zip(&panel) {
+                            *c = $add(*c, $mul(a, *b));
+                        }
+                    }
+                }
+                for (i, row) in acc.iter().enumerate() {
+                    for (j, v) in row.iter().enumerate() {
+                        let start = i * ldc + j * $lanes;
+                        let c = &mut c[start..start + $lanes];
+                        let sum = $add(load(c), *v);
+                        store(c, sum);
+                    }
+                }
+            }
+        }
+    };
+}
+
+simd!(
+    sse_f32,
+    "sse2",
+    f32,
+    4,
+    __m128,
+    _mm_loadu_ps,
+    _mm_storeu_ps,
+    _mm_set1_ps,
+    _mm_setzero_ps,
+    _mm_add_ps,
+    _mm_sub_ps,
+    _mm_mul_ps
+);
+
+simd!(
+    sse2_f64,
+    "sse2",
+    f64,
+    2,
+    __m128d,
+    _mm_loadu_pd,
+    _mm_storeu_pd,
+    _mm_set1_pd,
+    _mm_setzero_pd,
+    _mm_add_pd,
+    _mm_sub_pd,
+    _mm_mul_pd
+);
+
+simd!(
+    avx_f32,
+    "avx",
+    f32,
+    8,
+    __m256,
+    _mm256_loadu_ps,
+    _mm256_storeu_ps,
+    _mm256_set1_ps,
+    _mm256_setzero_ps,
+    _mm256_add_ps,
+    _mm256_sub_ps,
+    _mm256_mul_ps
+);
+
+simd!(
+    avx_f64,
+    "avx",
+    f64,
+    4,
+    __m256d,
+    _mm256_loadu_pd,
+    _mm256_storeu_pd,
+    _mm256_set1_pd,
+    _mm256_setzero_pd,
+    _mm256_add_pd,
+    _mm256_sub_pd,
+    _mm256_mul_pd
+);
+
+/// Calls `$f` of the kernel module for the widest available instruction set up to `$isa`.
+macro_rules! dispatch {
+    ($isa:expr, $sse:ident, $avx:ident, $f:ident($($arg:expr),*)) => {
+        match $isa.min(Isa::detect()) {
+            // SAFETY: `Isa::detect` only returns instruction sets that the CPU supports.
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Avx => unsafe { $avx::$f($($arg),*) },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Isa::Sse2 => unsafe { $sse::$f($($arg),*) },
+            _ => scalar::$f($($arg),*),
+        }
+    };
+}
+
+macro_rules! kernels {
+    ($t:ty, $sse:ident, $avx:ident) => {
+        impl Kernels for $t {
+            fn dot(isa: Isa, x: &[$t], y: &[$t]) -> $t {
+                check_lengths(x.len(), y.len());
+                dispatch!(isa, $sse, $avx, dot(x, y))
+            }
+
+            fn dotc(isa: Isa, x: &[$t], y: &[$t]) -> $t {
+                Self::dot(isa, x, y)
+            }
+
+            fn axpy(isa: Isa, alpha: $t, x: &[$t], y: &mut [$t]) {
+                check_lengths(x.len(), y.len());
+                dispatch!(isa, $sse, $avx, axpy(alpha, x, y))
+            }
+
+            fn add_elementwise(isa: Isa, y: &mut [$t], x: &[$t]) {
+                check_lengths(x.len(), y.len());
+                dispatch!(isa, $sse, $avx, add(y, x))
+            }
+
+            fn sub_elementwise(isa: Isa, y: &mut [$t], x: &[$t]) {
+                check_lengths(x.len(), y.len());
+                dispatch!(isa, $sse, $avx, sub(y, x))
+            }
+
+            fn mul_elementwise(isa: Isa, y: &mut [$t], x: &[$t]) {
+                check_lengths(x.len(), y.len());
+                dispatch!(isa, $sse, $avx, mul(y, x))
+            }
+
+            fn gemm_kernel(isa: Isa, k: usize, a: &[$t], b: &[$t], c: &mut [$t], ldc: usize) {
+                check_gemm(k, a.len(), b.len(), c.len(), ldc);
+                dispatch!(isa, $sse, $avx, gemm_kernel(k, a, b, c, ldc))
+            }
+        }
+    };
+}
+
+kernels!(f32, sse_f32, avx_f32);
+kernels!(f64, sse2_f64, avx_f64);
+
+impl<T: Copy + Num + NumAssign + Neg<Output = T>> Kernels for Complex<T> {
+    fn dot(_: Isa, x: &[Self], y: &[Self]) -> Self {
+        check_lengths(x.len(), y.len());
+        scalar::dot(x, y)
+    }
+
+    fn dotc(_: Isa, x: &[Self], y: &[Self]) -> Self {
+        check_lengths(x.len(), y.len());
+        x.iter()
+            .zip(y)
+            .fold(Self::new(T::zero(), T::zero()), |acc, (x, y)| {
+                acc + x.conj() * *y
+            })
+    }
+
+    fn axpy(_: Isa, alpha: Self, x: &[Self], y: &mut [Self]) {
+        check_lengths(x.len(), y.len());
+        scalar::axpy(alpha, x, y)
+    }
+
+    fn add_elementwise(_: Isa, y: &mut [Self], x: &[Self]) {
+        check_lengths(x.len(), y.len());
+        scalar::add(y, x)
+    }
+
+    fn sub_elementwise(_: Isa, y: &mut [Self], x: &[Self]) {
+        check_lengths(x.len(), y.len());
+        scalar::sub(y, x)
+    }
+
+    fn mul_elementwise(_: Isa, y: &mut [Self], x: &[Self]) {
+        check_lengths(x.len(), y.len());
+        scalar::mul(y, x)
+    }
+
+    fn gemm_kernel(_: Isa, k: usize, a: &[Self], b: &[Self], c: &mut [Self], ldc: usize) {
+        check_gemm(k, a.len(), b.len(), c.len(), ldc);
+        scalar::gemm_kernel(k, a, b, c, ldc)
+    }
+}
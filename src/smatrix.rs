@@ -0,0 +1,328 @@
+use crate::{error::Error, matrix::Matrix, svector::SVector, view::MatrixView, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign},
+};
+
+/// Stack-allocated matrix of `N` rows and `M` columns of type `K`, stored row by row. The shapes
+/// of the operands of arithmetic, [`SMatrix::mul_vec`] and [`SMatrix::mul_mat`] are checked at
+/// compile time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SMatrix<K, const N: usize, const M: usize> {
+    pub data: [[K; M]; N],
+}
+
+impl<K: Scalar, const N: usize, const M: usize> SMatrix<K, N, M> {
+    /// Creates an [`SMatrix`] filled with zeros.
+    pub fn zeros() -> Self {
+        SMatrix {
+            data: [[K::zero(); M]; N],
+        }
+    }
+
+    /// Returns the number of rows `N`.
+    pub fn rows(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of columns `M`.
+    pub fn columns(&self) -> usize {
+        M
+    }
+
+    /// Computes and returns the transpose of the current [`SMatrix`].
+    pub fn transpose(&self) -> SMatrix<K, M, N> {
+        let mut res = SMatrix::zeros();
+        for i in 0..N {
+            for j in 0..M {
+                res.data[j][i] = self.data[i][j];
+            }
+        }
+        res
+    }
+
+    /// Multiplies this [`SMatrix`] by the given [`SVector`].
+    /// ```
+    /// # use matrix::{smatrix::SMatrix, svector::SVector};
+    /// let u = SMatrix::from([[2., 0., 1.], [0., 2., 0.]]);
+    /// assert_eq!(u.mul_vec(&SVector::from([4., 2., 1.])), SVector::from([9., 4.]));
+    /// ```
+    pub fn mul_vec(&self, vec: &SVector<K, M>) -> SVector<K, N> {
+        let mut res = SVector::zeros();
+        for i in 0..N {
+            res[i] = (0..M).fold(K::zero(), |acc, j| acc + self.data[i][j] * vec[j]);
+        }
+        res
+    }
+
+    /// Multiplies this [`SMatrix`] by the given `SMatrix`, whose number of rows must equal the
+    /// number of columns of this one.
+    /// ```
+    /// # use matrix::smatrix::SMatrix;
+    /// let u = SMatrix::from([[3., -5.], [6., 8.]]);
+    /// let v = SMatrix::from([[2., 1., 0.], [4., 2., 1.]]);
+    /// assert_eq!(u.mul_mat(&v), SMatrix::from([[-14., -7., -5.], [44., 22., 8.]]));
+    /// ```
+    pub fn mul_mat<const P: usize>(&self, mat: &SMatrix<K, M, P>) -> SMatrix<K, N, P> {
+        let mut res = SMatrix::zeros();
+        for i in 0..N {
+            for k in 0..M {
+                let a = self.data[i][k];
+                for j in 0..P {
+                    res.data[i][j] += a * mat.data[k][j];
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<K: Scalar, const N: usize> SMatrix<K, N, N> {
+    /// Creates the identity [`SMatrix`].
+    pub fn identity() -> Self {
+        let mut res = SMatrix::zeros();
+        (0..N).for_each(|i| res.data[i][i] = K::one());
+        res
+    }
+
+    /// Computes the trace of the current [`SMatrix`].
+    pub fn trace(&self) -> K {
+        (0..N).fold(K::zero(), |acc, i| acc + self.data[i][i])
+    }
+
+    /// Calculates the determinant by Gaussian elimination with partial pivoting on a copy that
+    /// stays on the stack. It is zero if a pivot counts as zero by the tolerance of
+    /// [`Lu`](crate::lu::Lu), which is `N * ε` times the largest entry of its original row, and NaN
+    /// if a pivot column holds a NaN.
+    /// ```
+    /// # use matrix::smatrix::SMatrix;
+    /// let u = SMatrix::from([[2., 0., 0.], [0., 3., 0.], [1., 0., 4.]]);
+    /// assert_eq!(u.determinant(), 24.);
+    /// ```
+    pub fn determinant(&self) -> K {
+        let mut a = self.data;
+        let mut tolerances = self.tolerances();
+        let mut determinant = K::one();
+        for k in 0..N {
+            if (k..N).any(|i| a[i][k].modulus().is_nan()) {
+                return K::from_real(K::Real::nan());
+            }
+            let p = (k..N)
+                .max_by(|&x, &y| a[x][k].modulus().partial_cmp(&a[y][k].modulus()).unwrap())
+                .unwrap();
+            if a[p][k].modulus() <= tolerances[p] {
+                return K::zero();
+            }
+            if p != k {
+                a.swap(p, k);
+                tolerances.swap(p, k);
+                determinant = -determinant;
+            }
+            determinant *= a[k][k];
+            let pivot_row = a[k];
+            for row in a.iter_mut().skip(k + 1) {
+                let ratio = row[k] / pivot_row[k];
+                row.iter_mut()
+                    .zip(pivot_row)
+                    .skip(k)
+                    .for_each(|(x, p)| *x -= ratio * p);
+            }
+        }
+        determinant
+    }
+
+    /// Calculates the inverse by Gauss–Jordan elimination with partial pivoting. Returns an error if
+    /// the [`SMatrix`] is singular by the same tolerance as [`SMatrix::determinant`].
+    /// ```
+    /// # use matrix::smatrix::SMatrix;
+    /// let u = SMatrix::from([[2., 0.], [0., 4.]]);
+    /// assert_eq!(u.inverse().unwrap(), SMatrix::from([[0.5, 0.], [0., 0.25]]));
+    /// ```
+    pub fn inverse(&self) -> Result<Self> {
+        let mut a = self.data;
+        let mut inverse = Self::identity().data;
+        let mut tolerances = self.tolerances();
+        for k in 0..N {
+            if (k..N).any(|i| a[i][k].modulus().is_nan()) {
+                return Err(Error::Singular.into());
+            }
+            let p = (k..N)
+                .max_by(|&x, &y| a[x][k].modulus().partial_cmp(&a[y][k].modulus()).unwrap())
+                .unwrap();
+            if a[p][k].modulus() <= tolerances[p] {
+                return Err(Error::Singular.into());
+            }
+            a.swap(p, k);
+            inverse.swap(p, k);
+            tolerances.swap(p, k);
+            let pivot = a[k][k];
+            for j in 0..N {
+                a[k][j] /= pivot;
+                inverse[k][j] /= pivot;
+            }
+            for i in (0..N).filter(|&i| i != k) {
+                let ratio = a[i][k];
+                for j in 0..N {
+                    let (x, y) = (ratio * a[k][j], ratio * inverse[k][j]);
+                    a[i][j] -= x;
+                    inverse[i][j] -= y;
+                }
+            }
+        }
+        Ok(SMatrix { data: inverse })
+    }
+
+    /// Pivot tolerance of every row, `N * ε` times its largest entry.
+    fn tolerances(&self) -> [K::Real; N] {
+        let size = <K::Real as NumCast>::from(N).unwrap();
+        self.data.map(|row| {
+            row.iter()
+                .fold(K::Real::zero(), |max, x| max.max(x.modulus()))
+                * K::Real::epsilon()
+                * size
+        })
+    }
+}
+
+impl<K, const N: usize, const M: usize> Index<usize> for SMatrix<K, N, M> {
+    type Output = [K; M];
+
+    fn index(&self, i: usize) -> &[K; M] {
+        &self.data[i]
+    }
+}
+
+impl<K, const N: usize, const M: usize> IndexMut<usize> for SMatrix<K, N, M> {
+    fn index_mut(&mut self, i: usize) -> &mut [K; M] {
+        &mut self.data[i]
+    }
+}
+
+impl<K, const N: usize, const M: usize> Index<(usize, usize)> for SMatrix<K, N, M> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        &self.data[i][j]
+    }
+}
+
+impl<K, const N: usize, const M: usize> IndexMut<(usize, usize)> for SMatrix<K, N, M> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        &mut self.data[i][j]
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> AddAssign for SMatrix<K, N, M> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.data
+            .iter_mut()
+            .flatten()
+            .zip(rhs.data.iter().flatten())
+            .for_each(|(u, v)| *u += *v);
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> Add for SMatrix<K, N, M> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> SubAssign for SMatrix<K, N, M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.data
+            .iter_mut()
+            .flatten()
+            .zip(rhs.data.iter().flatten())
+            .for_each(|(u, v)| *u -= *v);
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> Sub for SMatrix<K, N, M> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> MulAssign<K> for SMatrix<K, N, M> {
+    fn mul_assign(&mut self, rhs: K) {
+        self.data.iter_mut().flatten().for_each(|u| *u *= rhs);
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> Mul<K> for SMatrix<K, N, M> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: K) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<K, const N: usize, const M: usize> From<[[K; M]; N]> for SMatrix<K, N, M> {
+    fn from(data: [[K; M]; N]) -> Self {
+        SMatrix { data }
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> From<SMatrix<K, N, M>> for Matrix<K> {
+    fn from(value: SMatrix<K, N, M>) -> Self {
+        Matrix::from(value.data)
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> TryFrom<MatrixView<'_, K>> for SMatrix<K, N, M> {
+    type Error = anyhow::Error;
+
+    /// Copies a view of shape `N`x`M`, returning an error for any other shape.
+    fn try_from(value: MatrixView<'_, K>) -> Result<Self> {
+        if value.rows() != N {
+            return Err(Error::DimensionMismatch {
+                expected: N,
+                found: value.rows(),
+            }
+            .into());
+        }
+        if value.columns() != M {
+            return Err(Error::DimensionMismatch {
+                expected: M,
+                found: value.columns(),
+            }
+            .into());
+        }
+        let mut res = SMatrix::zeros();
+        for i in 0..N {
+            for j in 0..M {
+                res.data[i][j] = value[(i, j)];
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl<K: Scalar, const N: usize, const M: usize> TryFrom<&Matrix<K>> for SMatrix<K, N, M> {
+    type Error = anyhow::Error;
+
+    /// Copies a [`Matrix`] of shape `N`x`M`, returning an error for any other shape.
+    fn try_from(value: &Matrix<K>) -> Result<Self> {
+        SMatrix::try_from(MatrixView::from(value))
+    }
+}
+
+impl<K: fmt::Debug, const N: usize, const M: usize> fmt::Display for SMatrix<K, N, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if M == 0 {
+            write!(f, "[]")
+        } else {
+            self.data.iter().try_for_each(|v| writeln!(f, "{v:?}"))
+        }
+    }
+}
@@ -0,0 +1,622 @@
+use crate::{iterative::LinearOperator, matrix::Matrix, vector::Vector, view::MatrixView, Scalar};
+use num::traits::float::FloatCore;
+use std::ops::Add;
+
+/// Sparse matrix builder in coordinate (triplet) format. Entries can be pushed in any order and
+/// the same position may be pushed several times, as happens when assembling finite-element
+/// matrices: duplicates are summed when converting to [`Csr`] or [`Csc`].
+/// ```
+/// # use matrix::{matrix::Matrix, sparse::Coo};
+/// let mut coo = Coo::new(2, 2);
+/// coo.push(0, 0, 1.);
+/// coo.push(1, 0, 2.);
+/// coo.push(0, 0, 3.);
+/// assert_eq!(coo.to_csr().to_matrix(), Matrix::from([[4., 0.], [2., 0.]]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Coo<K> {
+    m: usize,
+    n: usize,
+    entries: Vec<(usize, usize, K)>,
+}
+
+/// Sparse matrix in compressed sparse row format. The column indices of row `i` are stored in
+/// `indices[indptr[i]..indptr[i + 1]]` in increasing order, next to their values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Csr<K> {
+    m: usize,
+    n: usize,
+    storage: Compressed<K>,
+}
+
+/// Sparse matrix in compressed sparse column format. The row indices of column `j` are stored in
+/// `indices[indptr[j]..indptr[j + 1]]` in increasing order, next to their values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Csc<K> {
+    m: usize,
+    n: usize,
+    storage: Compressed<K>,
+}
+
+/// Compressed storage shared by [`Csr`] (outer dimension: rows) and [`Csc`] (outer dimension:
+/// columns). Reinterpreting it in the other format yields the transpose.
+#[derive(Clone, Debug, PartialEq)]
+struct Compressed<K> {
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<K>,
+}
+
+/// Storage of a `0`x`0` matrix, whose `indptr` still holds the `outer + 1 = 1` offsets.
+impl<K> Default for Compressed<K> {
+    fn default() -> Self {
+        Compressed {
+            indptr: vec![0],
+            indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<K: Scalar> Coo<K> {
+    /// Creates an empty builder for a matrix of `m` rows and `n` columns.
+    pub fn new(m: usize, n: usize) -> Coo<K> {
+        Coo {
+            m,
+            n,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the number of rows `m`.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns `n`.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of pushed entries, duplicates included.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Adds `value` to the entry at row `i` and column `j`.
+    pub fn push(&mut self, i: usize, j: usize, value: K) {
+        assert!(i < self.m && j < self.n, "index out of bounds");
+        self.entries.push((i, j, value));
+    }
+
+    /// Compresses the entries into a [`Csr`] matrix, summing duplicates.
+    pub fn to_csr(&self) -> Csr<K> {
+        Csr {
+            m: self.m,
+            n: self.n,
+            storage: Compressed::from_entries(self.m, self.entries.clone()),
+        }
+    }
+
+    /// Compresses the entries into a [`Csc`] matrix, summing duplicates.
+    pub fn to_csc(&self) -> Csc<K> {
+        let entries = self.entries.iter().map(|&(i, j, v)| (j, i, v)).collect();
+        Csc {
+            m: self.m,
+            n: self.n,
+            storage: Compressed::from_entries(self.n, entries),
+        }
+    }
+}
+
+impl<K: Scalar> Extend<(usize, usize, K)> for Coo<K> {
+    fn extend<T: IntoIterator<Item = (usize, usize, K)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|(i, j, v)| self.push(i, j, v));
+    }
+}
+
+impl<K: Scalar> Csr<K> {
+    /// Returns the number of rows `m`.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns `n`.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Returns the row pointers, of length `m + 1`.
+    pub fn indptr(&self) -> &[usize] {
+        &self.storage.indptr
+    }
+
+    /// Returns the column index of every stored entry.
+    pub fn indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Returns the value of every stored entry.
+    pub fn values(&self) -> &[K] {
+        &self.storage.values
+    }
+
+    /// Returns the column indices and values of the stored entries of row `i`.
+    pub fn row(&self, i: usize) -> (&[usize], &[K]) {
+        self.storage.lane(i)
+    }
+
+    /// Returns the element at row `i` and column `j`, which is zero if it is not stored.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.m && j < self.n, "index out of bounds");
+        self.storage.get(i, j)
+    }
+
+    /// Checks whether the [`Csr`] matrix is square and equal to its conjugate transpose, up to
+    /// rounding errors, like [`Matrix::is_hermitian`].
+    pub fn is_hermitian(&self) -> bool {
+        self.m == self.n && self.storage.is_hermitian()
+    }
+
+    /// Multiplies this [`Csr`] matrix by the given dense [`Vector`].
+    /// ```
+    /// # use matrix::{matrix::Matrix, sparse::Csr, vector::Vector};
+    /// let u = Csr::from(&Matrix::from([[2., 0., 1.], [0., 3., 0.]]));
+    /// assert_eq!(u.mul_vec(&Vector::from([1., 2., 3.])), Vector::from([5., 6.]));
+    /// ```
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        (0..self.m)
+            .map(|i| {
+                let (indices, values) = self.row(i);
+                indices
+                    .iter()
+                    .zip(values)
+                    .fold(K::zero(), |acc, (&j, &v)| acc + v * vec[j])
+            })
+            .collect()
+    }
+
+    /// Multiplies this [`Csr`] matrix by the given dense [`Matrix`] or view.
+    pub fn mul_mat<'a>(&self, mat: impl Into<MatrixView<'a, K>>) -> Matrix<K>
+    where
+        K: 'a,
+    {
+        let mat = mat.into();
+        assert_eq!(
+            self.n,
+            mat.rows(),
+            "inner dimensions of the matrices must agree"
+        );
+        let p = mat.columns();
+        let mut res = Matrix::from_vec(self.m, p, vec![K::zero(); self.m * p]).unwrap();
+        for (i, out) in res.row_iter_mut().enumerate() {
+            let (indices, values) = self.row(i);
+            for (&k, &a) in indices.iter().zip(values) {
+                out.iter_mut()
+                    .enumerate()
+                    .for_each(|(j, c)| *c += a * mat[(k, j)]);
+            }
+        }
+        res
+    }
+
+    /// Computes and returns the transpose of the current [`Csr`] matrix.
+    pub fn transpose(&self) -> Csr<K> {
+        Csr {
+            m: self.n,
+            n: self.m,
+            storage: self.storage.transpose(self.n),
+        }
+    }
+
+    /// Computes and returns the conjugate transpose of the current [`Csr`] matrix.
+    pub fn conjugate_transpose(&self) -> Csr<K> {
+        let mut res = self.transpose();
+        res.storage.values.iter_mut().for_each(|v| *v = v.conj());
+        res
+    }
+
+    /// Converts to the [`Csc`] format.
+    pub fn to_csc(&self) -> Csc<K> {
+        Csc {
+            m: self.m,
+            n: self.n,
+            storage: self.storage.transpose(self.n),
+        }
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let mut res = Matrix::from_vec(self.m, self.n, vec![K::zero(); self.m * self.n]).unwrap();
+        for (i, row) in res.row_iter_mut().enumerate() {
+            let (indices, values) = self.row(i);
+            indices.iter().zip(values).for_each(|(&j, &v)| row[j] = v);
+        }
+        res
+    }
+}
+
+impl<K: Scalar> Csc<K> {
+    /// Wraps already compressed columns whose row indices are sorted within every column.
+    pub(crate) fn from_parts(
+        m: usize,
+        n: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<K>,
+    ) -> Csc<K> {
+        debug_assert_eq!(indptr.len(), n + 1);
+        Csc {
+            m,
+            n,
+            storage: Compressed {
+                indptr,
+                indices,
+                values,
+            },
+        }
+    }
+
+    /// Returns the number of rows `m`.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns `n`.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Returns the column pointers, of length `n + 1`.
+    pub fn indptr(&self) -> &[usize] {
+        &self.storage.indptr
+    }
+
+    /// Returns the row index of every stored entry.
+    pub fn indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Returns the value of every stored entry.
+    pub fn values(&self) -> &[K] {
+        &self.storage.values
+    }
+
+    /// Returns the row indices and values of the stored entries of column `j`.
+    pub fn col(&self, j: usize) -> (&[usize], &[K]) {
+        self.storage.lane(j)
+    }
+
+    /// Returns the element at row `i` and column `j`, which is zero if it is not stored.
+    pub fn get(&self, i: usize, j: usize) -> K {
+        assert!(i < self.m && j < self.n, "index out of bounds");
+        self.storage.get(j, i)
+    }
+
+    /// Checks whether the [`Csc`] matrix is square and equal to its conjugate transpose, up to
+    /// rounding errors, like [`Matrix::is_hermitian`].
+    pub fn is_hermitian(&self) -> bool {
+        self.m == self.n && self.storage.is_hermitian()
+    }
+
+    /// Multiplies this [`Csc`] matrix by the given dense [`Vector`].
+    /// ```
+    /// # use matrix::{matrix::Matrix, sparse::Csc, vector::Vector};
+    /// let u = Csc::from(&Matrix::from([[2., 0., 1.], [0., 3., 0.]]));
+    /// assert_eq!(u.mul_vec(&Vector::from([1., 2., 3.])), Vector::from([5., 6.]));
+    /// ```
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        let mut res = vec![K::zero(); self.m];
+        for (j, &x) in vec.iter().enumerate() {
+            let (indices, values) = self.col(j);
+            indices
+                .iter()
+                .zip(values)
+                .for_each(|(&i, &v)| res[i] += v * x);
+        }
+        Vector::from(res)
+    }
+
+    /// Multiplies this [`Csc`] matrix by the given dense [`Matrix`] or view.
+    pub fn mul_mat<'a>(&self, mat: impl Into<MatrixView<'a, K>>) -> Matrix<K>
+    where
+        K: 'a,
+    {
+        let mat = mat.into();
+        assert_eq!(
+            self.n,
+            mat.rows(),
+            "inner dimensions of the matrices must agree"
+        );
+        let p = mat.columns();
+        let mut res = Matrix::from_vec(self.m, p, vec![K::zero(); self.m * p]).unwrap();
+        for k in 0..self.n {
+            let (indices, values) = self.col(k);
+            for (&i, &a) in indices.iter().zip(values) {
+                (0..p).for_each(|j| res[(i, j)] += a * mat[(k, j)]);
+            }
+        }
+        res
+    }
+
+    /// Computes and returns the transpose of the current [`Csc`] matrix.
+    pub fn transpose(&self) -> Csc<K> {
+        Csc {
+            m: self.n,
+            n: self.m,
+            storage: self.storage.transpose(self.m),
+        }
+    }
+
+    /// Computes and returns the conjugate transpose of the current [`Csc`] matrix.
+    pub fn conjugate_transpose(&self) -> Csc<K> {
+        let mut res = self.transpose();
+        res.storage.values.iter_mut().for_each(|v| *v = v.conj());
+        res
+    }
+
+    /// Converts to the [`Csr`] format.
+    pub fn to_csr(&self) -> Csr<K> {
+        Csr {
+            m: self.m,
+            n: self.n,
+            storage: self.storage.transpose(self.m),
+        }
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let mut res = Matrix::from_vec(self.m, self.n, vec![K::zero(); self.m * self.n]).unwrap();
+        for j in 0..self.n {
+            let (indices, values) = self.col(j);
+            indices
+                .iter()
+                .zip(values)
+                .for_each(|(&i, &v)| res[(i, j)] = v);
+        }
+        res
+    }
+}
+
+impl<K: Scalar> Compressed<K> {
+    /// Sorts `(outer, inner, value)` entries and sums the duplicates.
+    fn from_entries(outer: usize, mut entries: Vec<(usize, usize, K)>) -> Compressed<K> {
+        entries.sort_by_key(|&(o, i, _)| (o, i));
+        let mut res = Compressed {
+            indptr: vec![0; outer + 1],
+            indices: Vec::with_capacity(entries.len()),
+            values: Vec::with_capacity(entries.len()),
+        };
+        let mut last = None;
+        for (o, i, v) in entries {
+            if last == Some((o, i)) {
+                *res.values.last_mut().unwrap() += v;
+            } else {
+                res.indptr[o + 1] += 1;
+                res.indices.push(i);
+                res.values.push(v);
+                last = Some((o, i));
+            }
+        }
+        (0..outer).for_each(|o| res.indptr[o + 1] += res.indptr[o]);
+        res
+    }
+
+    /// Builds the storage of a dense matrix given as `outer` lanes of `inner` elements, dropping
+    /// the zeros.
+    fn from_dense(
+        outer: usize,
+        inner: usize,
+        element: impl Fn(usize, usize) -> K,
+    ) -> Compressed<K> {
+        let mut res = Compressed {
+            indptr: Vec::with_capacity(outer + 1),
+            indices: Vec::new(),
+            values: Vec::new(),
+        };
+        res.indptr.push(0);
+        for o in 0..outer {
+            for i in 0..inner {
+                let v = element(o, i);
+                if v != K::zero() {
+                    res.indices.push(i);
+                    res.values.push(v);
+                }
+            }
+            res.indptr.push(res.indices.len());
+        }
+        res
+    }
+
+    fn lane(&self, o: usize) -> (&[usize], &[K]) {
+        let range = self.indptr[o]..self.indptr[o + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    fn get(&self, o: usize, i: usize) -> K {
+        let (indices, values) = self.lane(o);
+        indices.binary_search(&i).map_or(K::zero(), |p| values[p])
+    }
+
+    /// Checks whether every stored entry is close to the conjugate of its mirrored entry, with the
+    /// tolerance of [`Matrix::is_hermitian`].
+    fn is_hermitian(&self) -> bool {
+        (0..self.indptr.len() - 1).all(|o| {
+            let (indices, values) = self.lane(o);
+            indices.iter().zip(values).all(|(&i, &a)| {
+                let b = self.get(i, o).conj();
+                (a - b).modulus() <= K::Real::epsilon() * (a.modulus() + b.modulus())
+            })
+        })
+    }
+
+    /// Returns the same matrix compressed along the other dimension, whose length is `inner`.
+    fn transpose(&self, inner: usize) -> Compressed<K> {
+        let mut indptr = vec![0; inner + 1];
+        self.indices.iter().for_each(|&i| indptr[i + 1] += 1);
+        (0..inner).for_each(|i| indptr[i + 1] += indptr[i]);
+        let mut next = indptr[..inner].to_vec();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![K::zero(); self.values.len()];
+        for o in 0..self.indptr.len() - 1 {
+            let (lane_indices, lane_values) = self.lane(o);
+            for (&i, &v) in lane_indices.iter().zip(lane_values) {
+                indices[next[i]] = o;
+                values[next[i]] = v;
+                next[i] += 1;
+            }
+        }
+        Compressed {
+            indptr,
+            indices,
+            values,
+        }
+    }
+
+    /// Merges the sorted lanes of both operands, summing the entries they have in common.
+    fn add(&self, rhs: &Compressed<K>) -> Compressed<K> {
+        let mut res = Compressed {
+            indptr: Vec::with_capacity(self.indptr.len()),
+            indices: Vec::with_capacity(self.indices.len() + rhs.indices.len()),
+            values: Vec::with_capacity(self.values.len() + rhs.values.len()),
+        };
+        res.indptr.push(0);
+        for o in 0..self.indptr.len() - 1 {
+            let (a_indices, a_values) = self.lane(o);
+            let (b_indices, b_values) = rhs.lane(o);
+            let (mut p, mut q) = (0, 0);
+            while p < a_indices.len() || q < b_indices.len() {
+                let a = a_indices.get(p).copied().unwrap_or(usize::MAX);
+                let b = b_indices.get(q).copied().unwrap_or(usize::MAX);
+                if a < b {
+                    res.indices.push(a);
+                    res.values.push(a_values[p]);
+                    p += 1;
+                } else if b < a {
+                    res.indices.push(b);
+                    res.values.push(b_values[q]);
+                    q += 1;
+                } else {
+                    res.indices.push(a);
+                    res.values.push(a_values[p] + b_values[q]);
+                    p += 1;
+                    q += 1;
+                }
+            }
+            res.indptr.push(res.indices.len());
+        }
+        res
+    }
+}
+
+impl<K: Scalar> Add for Csr<K> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n));
+        Csr {
+            m: self.m,
+            n: self.n,
+            storage: self.storage.add(&rhs.storage),
+        }
+    }
+}
+
+impl<K: Scalar> Add for Csc<K> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n));
+        Csc {
+            m: self.m,
+            n: self.n,
+            storage: self.storage.add(&rhs.storage),
+        }
+    }
+}
+
+/// Copies the nonzero elements of a dense view.
+impl<K: Scalar> From<MatrixView<'_, K>> for Csr<K> {
+    fn from(value: MatrixView<'_, K>) -> Self {
+        Csr {
+            m: value.rows(),
+            n: value.columns(),
+            storage: Compressed::from_dense(value.rows(), value.columns(), |i, j| value[(i, j)]),
+        }
+    }
+}
+
+impl<K: Scalar> From<&Matrix<K>> for Csr<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        Csr::from(value.as_view())
+    }
+}
+
+/// Copies the nonzero elements of a dense view.
+impl<K: Scalar> From<MatrixView<'_, K>> for Csc<K> {
+    fn from(value: MatrixView<'_, K>) -> Self {
+        Csc {
+            m: value.rows(),
+            n: value.columns(),
+            storage: Compressed::from_dense(value.columns(), value.rows(), |j, i| value[(i, j)]),
+        }
+    }
+}
+
+impl<K: Scalar> From<&Matrix<K>> for Csc<K> {
+    fn from(value: &Matrix<K>) -> Self {
+        Csc::from(value.as_view())
+    }
+}
+
+impl<K: Scalar> From<&Csr<K>> for Matrix<K> {
+    fn from(value: &Csr<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K: Scalar> From<&Csc<K>> for Matrix<K> {
+    fn from(value: &Csc<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K: Scalar> LinearOperator<K> for Csr<K> {
+    fn rows(&self) -> usize {
+        self.m
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
+
+impl<K: Scalar> LinearOperator<K> for Csc<K> {
+    fn rows(&self) -> usize {
+        self.m
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+}
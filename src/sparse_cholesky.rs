@@ -0,0 +1,260 @@
+use crate::{
+    error::Error,
+    ordering::Ordering,
+    sparse::{Coo, Csc},
+    vector::Vector,
+    Scalar,
+};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast};
+
+/// Symbolic analysis of a sparse Cholesky factorization: the fill-reducing permutation, the
+/// elimination tree and the number of nonzeros in every column of `L`. It only depends on the
+/// sparsity pattern of `A`, so one analysis can factorize every matrix sharing that pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolicCholesky {
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    permutation: Vec<usize>,
+    inverse: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    colptr: Vec<usize>,
+}
+
+/// Sparse Cholesky decomposition `PAPᵀ = LLᴴ` of a Hermitian positive-definite [`Csc`] matrix,
+/// where `P` is the fill-reducing permutation chosen by the [`SymbolicCholesky`] analysis and `L`
+/// is lower triangular with a positive diagonal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseCholesky<K> {
+    symbolic: SymbolicCholesky,
+    l: Csc<K>,
+}
+
+impl SymbolicCholesky {
+    /// Analyzes the pattern of the given square [`Csc`] matrix, of which both triangles must be
+    /// stored.
+    pub fn new<K: Scalar>(a: &Csc<K>, ordering: Ordering) -> Result<SymbolicCholesky> {
+        if a.rows() != a.columns() {
+            return Err(Error::NotSquare {
+                rows: a.rows(),
+                columns: a.columns(),
+            }
+            .into());
+        }
+        let n = a.columns();
+        let permutation = ordering.permutation(a);
+        let mut inverse = vec![0; n];
+        permutation
+            .iter()
+            .enumerate()
+            .for_each(|(k, &i)| inverse[i] = k);
+        let c = permuted_upper(a, &inverse);
+
+        // Liu's algorithm, with path compression through `ancestor`.
+        let mut parent = vec![None; n];
+        let mut ancestor: Vec<Option<usize>> = vec![None; n];
+        for k in 0..n {
+            for &i in c.col(k).0.iter().filter(|&&i| i < k) {
+                let mut i = i;
+                loop {
+                    match ancestor[i].replace(k) {
+                        None => {
+                            parent[i] = Some(k);
+                            break;
+                        }
+                        Some(next) if next == k => break,
+                        Some(next) => i = next,
+                    }
+                }
+            }
+        }
+
+        // Row `k` of `L` has a nonzero in every column of its elimination subtree.
+        let mut counts = vec![1; n];
+        let mut marks = vec![usize::MAX; n];
+        for k in 0..n {
+            ereach(&c, k, &parent, &mut marks)
+                .into_iter()
+                .for_each(|i| counts[i] += 1);
+        }
+        let mut colptr = vec![0; n + 1];
+        (0..n).for_each(|j| colptr[j + 1] = colptr[j] + counts[j]);
+
+        Ok(SymbolicCholesky {
+            indptr: a.indptr().to_vec(),
+            indices: a.indices().to_vec(),
+            permutation,
+            inverse,
+            parent,
+            colptr,
+        })
+    }
+
+    /// Returns the fill-reducing permutation: row and column `k` of `PAPᵀ` are row and column
+    /// `permutation()[k]` of `A`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the parent of every column in the elimination tree of `PAPᵀ`, or `None` for roots.
+    pub fn parent(&self) -> &[Option<usize>] {
+        &self.parent
+    }
+
+    /// Returns the number of nonzeros the factor `L` will hold.
+    pub fn nnz(&self) -> usize {
+        self.colptr[self.colptr.len() - 1]
+    }
+
+    /// Computes the numeric factorization of a [`Csc`] matrix with the analyzed pattern, using the
+    /// up-looking algorithm. Returns an error if its pattern differs from the analyzed one, or if
+    /// it is not Hermitian positive-definite.
+    pub fn factorize<K: Scalar>(&self, a: &Csc<K>) -> Result<SparseCholesky<K>> {
+        let n = self.permutation.len();
+        if a.rows() != n || a.indptr() != self.indptr || a.indices() != self.indices {
+            return Err(Error::PatternMismatch.into());
+        }
+        if !a.is_hermitian() {
+            return Err(Error::NotSymmetric.into());
+        }
+        let c = permuted_upper(a, &self.inverse);
+        let size = <K::Real as NumCast>::from(n).unwrap();
+
+        let mut next = self.colptr[..n].to_vec();
+        let mut indices = vec![0; self.nnz()];
+        let mut values = vec![K::zero(); self.nnz()];
+        let mut x = vec![K::zero(); n];
+        let mut marks = vec![usize::MAX; n];
+        for k in 0..n {
+            let pattern = ereach(&c, k, &self.parent, &mut marks);
+            let (rows, entries) = c.col(k);
+            rows.iter().zip(entries).for_each(|(&i, &v)| x[i] = v);
+            let mut d = x[k].re();
+            x[k] = K::zero();
+            for i in pattern {
+                // Solves for the conjugate of `L(k, i)`, then scatters it into the rest of row `k`.
+                let lki = x[i] / values[self.colptr[i]];
+                x[i] = K::zero();
+                for p in (self.colptr[i] + 1)..next[i] {
+                    x[indices[p]] -= values[p] * lki;
+                }
+                d -= lki.modulus_sqr();
+                indices[next[i]] = k;
+                values[next[i]] = lki.conj();
+                next[i] += 1;
+            }
+            let akk = c.get(k, k);
+            if d <= size * K::Real::epsilon() * akk.modulus() || d.is_nan() {
+                return Err(Error::NotPositiveDefinite.into());
+            }
+            indices[next[k]] = k;
+            values[next[k]] = K::from_real(d.sqrt());
+            next[k] += 1;
+        }
+        Ok(SparseCholesky {
+            symbolic: self.clone(),
+            l: Csc::from_parts(n, n, self.colptr.clone(), indices, values),
+        })
+    }
+}
+
+impl<K: Scalar> SparseCholesky<K> {
+    /// Analyzes and factorizes the given [`Csc`] matrix. Use [`SymbolicCholesky::factorize`] to
+    /// factorize further matrices with the same pattern without repeating the analysis.
+    /// ```
+    /// # use matrix::{matrix::Matrix, ordering::Ordering, sparse::Csc, sparse_cholesky::SparseCholesky, vector::Vector};
+    /// let a = Csc::from(&Matrix::from([[4., 2., 0.], [2., 10., 0.], [0., 0., 9.]]));
+    /// let cholesky = SparseCholesky::new(&a, Ordering::Natural).unwrap();
+    /// let x = cholesky.solve(&Vector::from([6., 12., 9.])).unwrap();
+    /// assert_eq!(x, Vector::from([1., 1., 1.]));
+    /// ```
+    pub fn new(a: &Csc<K>, ordering: Ordering) -> Result<SparseCholesky<K>> {
+        SymbolicCholesky::new(a, ordering)?.factorize(a)
+    }
+
+    /// Returns the symbolic analysis the factorization was computed with.
+    pub fn symbolic(&self) -> &SymbolicCholesky {
+        &self.symbolic
+    }
+
+    /// Returns the lower triangular factor `L` of `PAPᵀ`.
+    pub fn l(&self) -> &Csc<K> {
+        &self.l
+    }
+
+    /// Solves `Ax = b` for `x` by permuting `b`, substituting forward with `L` and backward with
+    /// `Lᴴ`, and permuting the result back.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.l.columns();
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        let permutation = self.symbolic.permutation();
+        let mut y: Vec<K> = permutation.iter().map(|&i| b[i]).collect();
+        for j in 0..n {
+            let (rows, values) = self.l.col(j);
+            y[j] /= values[0];
+            for (&i, &v) in rows.iter().zip(values).skip(1) {
+                let tmp = v * y[j];
+                y[i] -= tmp;
+            }
+        }
+        for j in (0..n).rev() {
+            let (rows, values) = self.l.col(j);
+            for (&i, &v) in rows.iter().zip(values).skip(1) {
+                let tmp = v.conj() * y[i];
+                y[j] -= tmp;
+            }
+            y[j] /= values[0];
+        }
+        let mut x = vec![K::zero(); n];
+        permutation.iter().zip(y).for_each(|(&i, v)| x[i] = v);
+        Ok(Vector::from(x))
+    }
+}
+
+/// Returns the upper triangle of `PAPᵀ`, where `inverse[i]` is the new index of row and column
+/// `i`, taking every entry from the upper triangle of `A`.
+fn permuted_upper<K: Scalar>(a: &Csc<K>, inverse: &[usize]) -> Csc<K> {
+    let mut coo = Coo::new(a.rows(), a.columns());
+    for j in 0..a.columns() {
+        let (rows, values) = a.col(j);
+        for (&i, &v) in rows.iter().zip(values).filter(|(&i, _)| i <= j) {
+            let (pi, pj) = (inverse[i], inverse[j]);
+            if pi <= pj {
+                coo.push(pi, pj, v);
+            } else {
+                coo.push(pj, pi, v.conj());
+            }
+        }
+    }
+    coo.to_csc()
+}
+
+/// Returns the pattern of row `k` of `L` left of the diagonal, which is the set of nodes of the
+/// elimination tree reachable from the entries of column `k` of the upper triangle `c`, in
+/// topological order: every node comes before its ancestors.
+fn ereach<K: Scalar>(
+    c: &Csc<K>,
+    k: usize,
+    parent: &[Option<usize>],
+    marks: &mut [usize],
+) -> Vec<usize> {
+    marks[k] = k;
+    let mut paths = Vec::new();
+    for &i in c.col(k).0.iter().filter(|&&i| i < k) {
+        let mut path = Vec::new();
+        let mut i = Some(i);
+        while let Some(j) = i.filter(|&j| marks[j] != k) {
+            path.push(j);
+            marks[j] = k;
+            i = parent[j];
+        }
+        paths.push(path);
+    }
+    paths.into_iter().rev().flatten().collect()
+}
@@ -0,0 +1,259 @@
+use crate::{error::Error, ordering::Ordering, sparse::Csc, vector::Vector, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, Zero};
+
+/// Symbolic analysis of a sparse LU factorization: the fill-reducing column permutation, computed
+/// from the pattern of `A + Aᵀ`. The row permutation is only known once partial pivoting has seen
+/// the values, so it is left to [`SymbolicLu::factorize`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymbolicLu {
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    column_permutation: Vec<usize>,
+}
+
+/// Sparse LU decomposition `PAQ = LU` of a square [`Csc`] matrix computed with partial pivoting.
+/// `L` is unit lower triangular, `U` is upper triangular, `Q` is the fill-reducing column
+/// permutation of the [`SymbolicLu`] analysis and `P` is the row permutation chosen by pivoting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseLu<K> {
+    symbolic: SymbolicLu,
+    l: Csc<K>,
+    u: Csc<K>,
+    row_permutation: Vec<usize>,
+}
+
+impl SymbolicLu {
+    /// Analyzes the pattern of the given square [`Csc`] matrix.
+    pub fn new<K: Scalar>(a: &Csc<K>, ordering: Ordering) -> Result<SymbolicLu> {
+        if a.rows() != a.columns() {
+            return Err(Error::NotSquare {
+                rows: a.rows(),
+                columns: a.columns(),
+            }
+            .into());
+        }
+        Ok(SymbolicLu {
+            indptr: a.indptr().to_vec(),
+            indices: a.indices().to_vec(),
+            column_permutation: ordering.permutation(a),
+        })
+    }
+
+    /// Returns the column permutation `Q`: column `k` of `AQ` is column `column_permutation()[k]`
+    /// of `A`.
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.column_permutation
+    }
+
+    /// Computes the numeric factorization of a [`Csc`] matrix with the analyzed pattern, using the
+    /// left-looking algorithm of Gilbert and Peierls. Returns an error if its pattern differs from
+    /// the analyzed one, or if it is singular: a pivot counts as zero when it is below `n * ε`
+    /// times the largest entry of its original column.
+    pub fn factorize<K: Scalar>(&self, a: &Csc<K>) -> Result<SparseLu<K>> {
+        let n = self.column_permutation.len();
+        if a.rows() != n || a.indptr() != self.indptr || a.indices() != self.indices {
+            return Err(Error::PatternMismatch.into());
+        }
+        let size = <K::Real as NumCast>::from(n).unwrap();
+
+        let (mut lp, mut li, mut lx) = (vec![0], Vec::new(), Vec::new());
+        let (mut up, mut ui, mut ux) = (vec![0], Vec::new(), Vec::new());
+        // `pivots[i]` is the step at which row `i` of `A` was chosen as pivot.
+        let mut pivots: Vec<Option<usize>> = vec![None; n];
+        let mut x = vec![K::zero(); n];
+        let mut marked = vec![false; n];
+        for (k, &column) in self.column_permutation.iter().enumerate() {
+            // Solves `Lx = A(:, column)` over the rows reachable from the column's pattern.
+            let (rows, values) = a.col(column);
+            let pattern = reach(&lp, &li, &pivots, rows, &mut marked);
+            rows.iter().zip(values).for_each(|(&i, &v)| x[i] = v);
+            for &j in &pattern {
+                if let Some(jj) = pivots[j] {
+                    for p in (lp[jj] + 1)..lp[jj + 1] {
+                        let tmp = lx[p] * x[j];
+                        x[li[p]] -= tmp;
+                    }
+                }
+            }
+
+            let mut pivot_row = None;
+            let mut max = K::Real::zero();
+            for &i in &pattern {
+                match pivots[i] {
+                    Some(r) => {
+                        ui.push(r);
+                        ux.push(x[i]);
+                    }
+                    None if x[i].modulus() > max || pivot_row.is_none() => {
+                        pivot_row = Some(i);
+                        max = x[i].modulus();
+                    }
+                    None => (),
+                }
+            }
+            let tolerance = values
+                .iter()
+                .fold(K::Real::zero(), |max, v| max.max(v.modulus()))
+                * K::Real::epsilon()
+                * size;
+            let pivot_row = match pivot_row {
+                Some(i) if max > tolerance => i,
+                _ => return Err(Error::Singular.into()),
+            };
+            let pivot = x[pivot_row];
+            ui.push(k);
+            ux.push(pivot);
+            up.push(ui.len());
+
+            pivots[pivot_row] = Some(k);
+            li.push(pivot_row);
+            lx.push(K::one());
+            for &i in &pattern {
+                if pivots[i].is_none() {
+                    li.push(i);
+                    lx.push(x[i] / pivot);
+                }
+                x[i] = K::zero();
+            }
+            lp.push(li.len());
+        }
+
+        let mut row_permutation = vec![0; n];
+        let pivots: Vec<usize> = pivots.into_iter().map(Option::unwrap).collect();
+        pivots
+            .iter()
+            .enumerate()
+            .for_each(|(i, &k)| row_permutation[k] = i);
+        li.iter_mut().for_each(|i| *i = pivots[*i]);
+        sort_columns(&lp, &mut li, &mut lx);
+        sort_columns(&up, &mut ui, &mut ux);
+        Ok(SparseLu {
+            symbolic: self.clone(),
+            l: Csc::from_parts(n, n, lp, li, lx),
+            u: Csc::from_parts(n, n, up, ui, ux),
+            row_permutation,
+        })
+    }
+}
+
+impl<K: Scalar> SparseLu<K> {
+    /// Analyzes and factorizes the given [`Csc`] matrix. Use [`SymbolicLu::factorize`] to
+    /// factorize further matrices with the same pattern without repeating the analysis.
+    /// ```
+    /// # use matrix::{matrix::Matrix, ordering::Ordering, sparse::Csc, sparse_lu::SparseLu, vector::Vector};
+    /// let a = Csc::from(&Matrix::from([[0., 2., 0.], [1., 0., 0.], [0., 1., 3.]]));
+    /// let lu = SparseLu::new(&a, Ordering::default()).unwrap();
+    /// assert_eq!(lu.solve(&Vector::from([2., 1., 4.])).unwrap(), Vector::from([1., 1., 1.]));
+    /// ```
+    pub fn new(a: &Csc<K>, ordering: Ordering) -> Result<SparseLu<K>> {
+        SymbolicLu::new(a, ordering)?.factorize(a)
+    }
+
+    /// Returns the symbolic analysis the factorization was computed with.
+    pub fn symbolic(&self) -> &SymbolicLu {
+        &self.symbolic
+    }
+
+    /// Returns the unit lower triangular factor `L`.
+    pub fn l(&self) -> &Csc<K> {
+        &self.l
+    }
+
+    /// Returns the upper triangular factor `U`.
+    pub fn u(&self) -> &Csc<K> {
+        &self.u
+    }
+
+    /// Returns the row permutation `P`: row `k` of `PA` is row `row_permutation()[k]` of `A`.
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.row_permutation
+    }
+
+    /// Solves `Ax = b` for `x` using the factorization.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = self.u.columns();
+        if b.len() != n {
+            return Err(Error::DimensionMismatch {
+                expected: n,
+                found: b.len(),
+            }
+            .into());
+        }
+        let mut y: Vec<K> = self.row_permutation.iter().map(|&i| b[i]).collect();
+        for j in 0..n {
+            let (rows, values) = self.l.col(j);
+            for (&i, &v) in rows.iter().zip(values).skip(1) {
+                let tmp = v * y[j];
+                y[i] -= tmp;
+            }
+        }
+        for j in (0..n).rev() {
+            let (rows, values) = self.u.col(j);
+            let last = values.len() - 1;
+            y[j] /= values[last];
+            for (&i, &v) in rows.iter().zip(values).take(last) {
+                let tmp = v * y[j];
+                y[i] -= tmp;
+            }
+        }
+        let mut x = vec![K::zero(); n];
+        self.symbolic
+            .column_permutation
+            .iter()
+            .zip(y)
+            .for_each(|(&j, v)| x[j] = v);
+        Ok(Vector::from(x))
+    }
+}
+
+/// Returns the rows of `A` reached by a depth-first search from `start` through the columns of
+/// `L` computed so far, in topological order. Column `pivots[i]` of `L` holds the multipliers of
+/// pivot row `i`, whose own entry comes first.
+fn reach(
+    lp: &[usize],
+    li: &[usize],
+    pivots: &[Option<usize>],
+    start: &[usize],
+    marked: &mut [bool],
+) -> Vec<usize> {
+    let children = |j: usize| pivots[j].map_or(0..0, |jj| (lp[jj] + 1)..lp[jj + 1]);
+    let mut order = Vec::new();
+    for &root in start {
+        if marked[root] {
+            continue;
+        }
+        marked[root] = true;
+        let mut stack = vec![(root, children(root).start)];
+        while let Some((j, p)) = stack.pop() {
+            match (p..children(j).end).find(|&q| !marked[li[q]]) {
+                Some(q) => {
+                    stack.push((j, q + 1));
+                    marked[li[q]] = true;
+                    stack.push((li[q], children(li[q]).start));
+                }
+                None => order.push(j),
+            }
+        }
+    }
+    order.iter().for_each(|&j| marked[j] = false);
+    order.reverse();
+    order
+}
+
+/// Sorts the indices of every compressed column, keeping the values alongside.
+fn sort_columns<K: Copy>(indptr: &[usize], indices: &mut [usize], values: &mut [K]) {
+    for bounds in indptr.windows(2) {
+        let range = bounds[0]..bounds[1];
+        let mut column: Vec<(usize, K)> = indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(values[range.clone()].iter().copied())
+            .collect();
+        column.sort_by_key(|&(i, _)| i);
+        for (p, (i, v)) in range.zip(column) {
+            indices[p] = i;
+            values[p] = v;
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{error::Error, iterative::IterativeSolution, matrix::Matrix, vector::Vector, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, NumCast, One, Zero};
+
+/// The [`Vector`] norm used to measure residuals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Norm {
+    /// [`Vector::norm`].
+    #[default]
+    Euclidean,
+    /// [`Vector::norm_inf`].
+    Infinity,
+}
+
+/// Stopping criteria of the stationary iterative methods.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StationaryOptions<K: Scalar> {
+    /// The iteration stops once `‖b - Ax‖ <= tol * ‖b‖`.
+    pub tol: K::Real,
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+    /// The norm in which the residual is measured.
+    pub norm: Norm,
+}
+
+impl<K: Scalar> Default for StationaryOptions<K> {
+    fn default() -> Self {
+        StationaryOptions {
+            tol: <K::Real as NumCast>::from(1e-5).unwrap(),
+            max_iterations: 1000,
+            norm: Norm::default(),
+        }
+    }
+}
+
+/// Solves `Ax = b` with the Jacobi method, calling `callback` with the iteration number, the
+/// current iterate and its residual norm after every iteration. Returns an error if the method
+/// is known to diverge for the given [`Matrix`].
+/// ```
+/// # use matrix::{matrix::Matrix, stationary::jacobi, vector::Vector};
+/// let a = Matrix::<f32>::from([[4., 1.], [2., 5.]]);
+/// let res = jacobi(&a, &Vector::from([5., 7.]), &Default::default(), |_, _, _| {}).unwrap();
+/// assert!(res.converged());
+/// assert!((res.solution()[0] - 1.).abs() < 1e-4);
+/// ```
+pub fn jacobi<K, F>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    options: &StationaryOptions<K>,
+    callback: F,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    F: FnMut(usize, &Vector<K>, K::Real),
+{
+    iterate(a, b, None, options, callback)
+}
+
+/// Solves `Ax = b` with the Gauss–Seidel method, which is [`sor`] with `ω = 1`.
+pub fn gauss_seidel<K, F>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    options: &StationaryOptions<K>,
+    callback: F,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    F: FnMut(usize, &Vector<K>, K::Real),
+{
+    iterate(a, b, Some(K::Real::one()), options, callback)
+}
+
+/// Solves `Ax = b` with successive over-relaxation, calling `callback` after every iteration like
+/// [`jacobi`]. The real relaxation factor `omega` must lie in `(0, 2)`.
+/// ```
+/// # use matrix::{matrix::Matrix, stationary::sor, vector::Vector};
+/// let a = Matrix::from([[4., -1.], [-1., 4.]]);
+/// let mut log = vec![];
+/// let res = sor(&a, &Vector::from([3., 3.]), 1.1, &Default::default(), |k, _, r| log.push((k, r)))
+///     .unwrap();
+/// assert!(res.converged());
+/// assert_eq!(log.len(), res.iterations());
+/// ```
+pub fn sor<K, F>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    omega: K::Real,
+    options: &StationaryOptions<K>,
+    callback: F,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    F: FnMut(usize, &Vector<K>, K::Real),
+{
+    iterate(a, b, Some(omega), options, callback)
+}
+
+/// Runs the Jacobi method if `omega` is `None`, and SOR with relaxation factor `omega` otherwise.
+fn iterate<K, F>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    omega: Option<K::Real>,
+    options: &StationaryOptions<K>,
+    mut callback: F,
+) -> Result<IterativeSolution<K>>
+where
+    K: Scalar,
+    F: FnMut(usize, &Vector<K>, K::Real),
+{
+    check_convergence(a, b, omega)?;
+    let n = a.n;
+    let threshold = options.tol * norm(b.clone(), options.norm);
+    let mut x: Vector<K> = vec![K::zero(); n].into();
+    let mut residuals = vec![residual(a, b, &x, options.norm)];
+
+    for k in 1..=options.max_iterations {
+        if residuals[residuals.len() - 1] <= threshold {
+            break;
+        }
+        match omega {
+            None => {
+                x = (0..n)
+                    .map(|i| off_diagonal(a, b, &x, i) / a[i][i])
+                    .collect();
+            }
+            Some(omega) => {
+                let omega = K::from_real(omega);
+                for i in 0..n {
+                    let gauss_seidel = off_diagonal(a, b, &x, i) / a[i][i];
+                    x[i] = (K::one() - omega) * x[i] + omega * gauss_seidel;
+                }
+            }
+        }
+        residuals.push(residual(a, b, &x, options.norm));
+        callback(k, &x, residuals[k]);
+    }
+    let converged = residuals[residuals.len() - 1] <= threshold;
+    Ok(IterativeSolution::new(x, residuals, converged))
+}
+
+/// Checks up front that the iteration converges for every initial guess. Strict diagonal
+/// dominance guarantees this for Jacobi and for SOR with `0 < ω <= 1`, and positive definiteness
+/// for SOR with `0 < ω < 2`. Otherwise the spectral radius of the iteration matrix has to be below
+/// one. As in [`Lu`](crate::lu::Lu), a diagonal element that is NaN or not above `n * ε` times the
+/// largest entry of its row counts as zero.
+fn check_convergence<K: Scalar>(
+    a: &Matrix<K>,
+    b: &Vector<K>,
+    omega: Option<K::Real>,
+) -> Result<()> {
+    let n = a.n;
+    if a.m != n {
+        return Err(Error::NotSquare {
+            rows: a.m,
+            columns: n,
+        }
+        .into());
+    }
+    if b.len() != n {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            found: b.len(),
+        }
+        .into());
+    }
+    let size = <K::Real as NumCast>::from(n).unwrap();
+    if a.row_iter().enumerate().any(|(i, row)| {
+        let scale = row
+            .iter()
+            .fold(K::Real::zero(), |max, x| max.max(x.modulus()));
+        let pivot = row[i].modulus();
+        pivot <= size * K::Real::epsilon() * scale || pivot.is_nan()
+    }) {
+        return Err(Error::Singular.into());
+    }
+    if let Some(omega) = omega {
+        let two = K::Real::one() + K::Real::one();
+        if omega <= K::Real::zero() || omega >= two || omega.is_nan() {
+            return Err(Error::Divergent.into());
+        }
+    }
+    let dominant = (0..n).all(|i| {
+        let off = (0..n)
+            .filter(|&j| j != i)
+            .fold(K::Real::zero(), |acc, j| acc + a[i][j].modulus());
+        a[i][i].modulus() > off
+    });
+    if dominant && omega.is_none_or(|omega| omega <= K::Real::one()) {
+        return Ok(());
+    }
+    if omega.is_some() && a.cholesky().is_ok() {
+        return Ok(());
+    }
+    if iteration_matrix(a, omega).spectral_radius()? >= K::Real::one() {
+        return Err(Error::Divergent.into());
+    }
+    Ok(())
+}
+
+/// Builds the iteration matrix `T` of the method, such that every iteration maps the error `e`
+/// to `Te`. For Jacobi `T = -D⁻¹(L + U)`, and for SOR `T = (D + ωL)⁻¹((1 - ω)D - ωU)`.
+fn iteration_matrix<K: Scalar>(a: &Matrix<K>, omega: Option<K::Real>) -> Matrix<K> {
+    let n = a.n;
+    let mut t = Matrix::zeros(n, n);
+    match omega {
+        None => {
+            for i in 0..n {
+                for j in (0..n).filter(|&j| j != i) {
+                    t[i][j] = -a[i][j] / a[i][i];
+                }
+            }
+        }
+        Some(omega) => {
+            let omega = K::from_real(omega);
+            for j in 0..n {
+                for i in 0..n {
+                    let mut s = match i.cmp(&j) {
+                        std::cmp::Ordering::Less => -omega * a[i][j],
+                        std::cmp::Ordering::Equal => (K::one() - omega) * a[i][i],
+                        std::cmp::Ordering::Greater => K::zero(),
+                    };
+                    s = (0..i).fold(s, |acc, k| acc - omega * a[i][k] * t[k][j]);
+                    t[i][j] = s / a[i][i];
+                }
+            }
+        }
+    }
+    t
+}
+
+/// Computes `b[i] - Σ a[i][j] x[j]` over all `j != i`.
+fn off_diagonal<K: Scalar>(a: &Matrix<K>, b: &Vector<K>, x: &Vector<K>, i: usize) -> K {
+    (0..a.n)
+        .filter(|&j| j != i)
+        .fold(b[i], |acc, j| acc - a[i][j] * x[j])
+}
+
+fn residual<K: Scalar>(a: &Matrix<K>, b: &Vector<K>, x: &Vector<K>, kind: Norm) -> K::Real {
+    let r: Vector<K> = (0..a.m)
+        .map(|i| (0..a.n).fold(b[i], |acc, j| acc - a[i][j] * x[j]))
+        .collect();
+    norm(r, kind)
+}
+
+fn norm<K: Scalar>(v: Vector<K>, kind: Norm) -> K::Real {
+    match kind {
+        Norm::Euclidean => v.norm(),
+        Norm::Infinity => v.norm_inf(),
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{gemm::gemm, matrix::Matrix, view::MatrixView, Scalar};
+
+/// Default [`Algorithm::Strassen`] cutoff, below which the blocked product is faster than another
+/// level of recursion.
+pub const DEFAULT_CUTOFF: usize = 128;
+
+/// Algorithm used by [`MatrixView::mul_mat_with`] and
+/// [`Matrix::mul_mat_with`](crate::matrix::Matrix::mul_mat_with).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// The cache-blocked classical product of [`gemm`], which is what
+    /// [`MatrixView::mul_mat`] uses.
+    #[default]
+    Classical,
+    /// Strassen–Winograd recursion, which needs 7 instead of 8 half-size products per level and
+    /// so `O(n^2.81)` operations, at the cost of extra temporaries and a somewhat larger rounding
+    /// error. Blocks with a dimension of at most `cutoff` use the classical product.
+    Strassen { cutoff: usize },
+}
+
+/// Multiplies `a` by `b` with the Strassen–Winograd algorithm, recursing until a dimension drops
+/// to `cutoff` or below. An odd row or column is peeled off and multiplied classically, so any
+/// shape works without padding. Panics if the inner dimensions differ.
+/// ```
+/// # use matrix::{matrix::Matrix, strassen::strassen};
+/// let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+/// assert_eq!(strassen(u.as_view(), u.as_view(), 1), u.as_view().mul_mat(&u));
+/// ```
+pub fn strassen<K: Scalar>(a: MatrixView<'_, K>, b: MatrixView<'_, K>, cutoff: usize) -> Matrix<K> {
+    let (m, k, n) = (a.rows(), a.columns(), b.columns());
+    assert_eq!(k, b.rows(), "inner dimensions of the matrices must agree");
+    if m.min(k).min(n) <= cutoff.max(1) {
+        return a.mul_mat(b);
+    }
+
+    let (me, ke, ne) = (m & !1, k & !1, n & !1);
+    let mut c = Matrix::zeros(m, n);
+    c.view_mut(..me, ..ne)
+        .copy_from(&winograd(a.view(..me, ..ke), b.view(..ke, ..ne), cutoff));
+    if ke < k {
+        gemm(
+            K::one(),
+            a.view(..me, ke..),
+            b.view(ke.., ..ne),
+            K::one(),
+            c.view_mut(..me, ..ne),
+        );
+    }
+    if ne < n {
+        gemm(
+            K::one(),
+            a.view(..me, ..),
+            b.view(.., ne..),
+            K::zero(),
+            c.view_mut(..me, ne..),
+        );
+    }
+    if me < m {
+        gemm(
+            K::one(),
+            a.view(me.., ..),
+            b,
+            K::zero(),
+            c.view_mut(me.., ..),
+        );
+    }
+    c
+}
+
+/// One level of the Strassen–Winograd recursion for even dimensions, with 7 products and 15
+/// additions of quadrants.
+fn winograd<K: Scalar>(a: MatrixView<'_, K>, b: MatrixView<'_, K>, cutoff: usize) -> Matrix<K> {
+    let (mh, kh, nh) = (a.rows() / 2, a.columns() / 2, b.columns() / 2);
+    let (a11, a12) = (a.view(..mh, ..kh), a.view(..mh, kh..));
+    let (a21, a22) = (a.view(mh.., ..kh), a.view(mh.., kh..));
+    let (b11, b12) = (b.view(..kh, ..nh), b.view(..kh, nh..));
+    let (b21, b22) = (b.view(kh.., ..nh), b.view(kh.., nh..));
+
+    let mut s1 = a21.to_matrix();
+    s1 += a22;
+    let mut s2 = s1.clone();
+    s2 -= a11;
+    let mut s3 = a11.to_matrix();
+    s3 -= a21;
+    let mut s4 = a12.to_matrix();
+    s4 -= s2.as_view();
+    let mut t1 = b12.to_matrix();
+    t1 -= b11;
+    let mut t2 = b22.to_matrix();
+    t2 -= t1.as_view();
+    let mut t3 = b22.to_matrix();
+    t3 -= b12;
+    let mut t4 = t2.clone();
+    t4 -= b21;
+
+    let p1 = strassen(a11, b11, cutoff);
+    let mut u1 = strassen(a12, b21, cutoff);
+    u1 += p1.as_view();
+    let mut u2 = strassen(s2.as_view(), t2.as_view(), cutoff);
+    u2 += p1.as_view();
+    let mut u3 = strassen(s3.as_view(), t3.as_view(), cutoff);
+    u3 += u2.as_view();
+    let p5 = strassen(s1.as_view(), t1.as_view(), cutoff);
+    let mut u4 = u2;
+    u4 += p5.as_view();
+    let mut u5 = strassen(s4.as_view(), b22, cutoff);
+    u5 += u4.as_view();
+    let mut u6 = u3.clone();
+    u6 -= strassen(a22, t4.as_view(), cutoff).as_view();
+    let mut u7 = u3;
+    u7 += p5.as_view();
+
+    let mut c = Matrix::zeros(2 * mh, 2 * nh);
+    c.view_mut(..mh, ..nh).copy_from(&u1);
+    c.view_mut(..mh, nh..).copy_from(&u5);
+    c.view_mut(mh.., ..nh).copy_from(&u6);
+    c.view_mut(mh.., nh..).copy_from(&u7);
+    c
+}
@@ -0,0 +1,503 @@
+use crate::{error::Error, ldlt::Ldlt, matrix::Matrix, vector::Vector, RealScalar, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, Zero};
+use std::ops::{Index, IndexMut};
+
+/// Square [`Matrix`] whose elements below the diagonal are zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpperTriangular<K> {
+    matrix: Matrix<K>,
+}
+
+/// Square [`Matrix`] whose elements above the diagonal are zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LowerTriangular<K> {
+    matrix: Matrix<K>,
+}
+
+/// Symmetric matrix of size `n` in packed storage: only the lower triangle is kept, row by row, in
+/// `n * (n + 1) / 2` elements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Symmetric<K> {
+    data: Vec<K>,
+    n: usize,
+}
+
+/// Diagonal matrix, stored as its diagonal.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Diagonal<K> {
+    diag: Vec<K>,
+}
+
+impl<K: Scalar> UpperTriangular<K> {
+    /// Wraps a copy of the given square [`Matrix`], after checking that no element below the
+    /// diagonal exceeds `tol` in absolute value. Those elements are set to zero.
+    /// ```
+    /// # use matrix::{matrix::Matrix, structured::UpperTriangular};
+    /// let u = Matrix::from([[2., 1.], [1e-7, 3.]]);
+    /// assert_eq!(UpperTriangular::with_tol(&u, 1e-6).unwrap().determinant(), 6.);
+    /// assert!(UpperTriangular::with_tol(&u, 1e-8).is_err());
+    /// ```
+    pub fn with_tol(a: &Matrix<K>, tol: K::Real) -> Result<UpperTriangular<K>> {
+        check_square(a)?;
+        let mut matrix = a.clone();
+        for i in 0..a.rows() {
+            for j in 0..i {
+                if exceeds(matrix[i][j], tol) {
+                    return Err(Error::NotTriangular.into());
+                }
+                matrix[i][j] = K::zero();
+            }
+        }
+        Ok(UpperTriangular { matrix })
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Returns the underlying [`Matrix`].
+    pub fn as_matrix(&self) -> &Matrix<K> {
+        &self.matrix
+    }
+
+    /// Computes the transpose, which is lower triangular.
+    pub fn transpose(&self) -> LowerTriangular<K> {
+        LowerTriangular {
+            matrix: self.matrix.as_view().transpose().to_matrix(),
+        }
+    }
+
+    /// Calculates the determinant as the product of the diagonal.
+    pub fn determinant(&self) -> K {
+        (0..self.size()).fold(K::one(), |acc, i| acc * self.matrix[i][i])
+    }
+
+    /// Multiplies by the given [`Vector`], skipping the zeros below the diagonal.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let n = self.size();
+        assert_eq!(n, vec.len(), "dimensions of the operands must agree");
+        (0..n)
+            .map(|i| (i..n).fold(K::zero(), |acc, j| acc + self.matrix[i][j] * vec[j]))
+            .collect()
+    }
+
+    /// Multiplies by another [`UpperTriangular`] matrix, which only involves the elements on and
+    /// above the diagonal and yields an upper triangular result.
+    pub fn mul_mat(&self, rhs: &UpperTriangular<K>) -> UpperTriangular<K> {
+        let n = self.size();
+        assert_eq!(n, rhs.size(), "inner dimensions of the matrices must agree");
+        let mut matrix = Matrix::from_vec(n, n, vec![K::zero(); n * n]).unwrap();
+        for i in 0..n {
+            for j in i..n {
+                matrix[i][j] = (i..=j).fold(K::zero(), |acc, k| {
+                    acc + self.matrix[i][k] * rhs.matrix[k][j]
+                });
+            }
+        }
+        UpperTriangular { matrix }
+    }
+
+    /// Solves `Ux = b` for `x` by back substitution. Returns an error if a diagonal element is not
+    /// larger than the tolerance of [`TryFrom`], `ε` times the largest element in absolute value.
+    /// ```
+    /// # use matrix::{matrix::Matrix, structured::UpperTriangular, vector::Vector};
+    /// let u = UpperTriangular::try_from(&Matrix::from([[2., 1.], [0., 4.]])).unwrap();
+    /// assert_eq!(u.solve(&Vector::from([3., 4.])).unwrap(), Vector::from([1., 1.]));
+    /// ```
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = check_rhs(self.size(), b)?;
+        check_diagonal(&self.matrix)?;
+        let mut x = b.clone();
+        for i in (0..n).rev() {
+            let s = ((i + 1)..n).fold(x[i], |acc, j| acc - self.matrix[i][j] * x[j]);
+            x[i] = s / self.matrix[i][i];
+        }
+        Ok(x)
+    }
+}
+
+impl<K: Scalar> LowerTriangular<K> {
+    /// Wraps a copy of the given square [`Matrix`], after checking that no element above the
+    /// diagonal exceeds `tol` in absolute value. Those elements are set to zero.
+    pub fn with_tol(a: &Matrix<K>, tol: K::Real) -> Result<LowerTriangular<K>> {
+        check_square(a)?;
+        let mut matrix = a.clone();
+        for i in 0..a.rows() {
+            for j in (i + 1)..a.columns() {
+                if exceeds(matrix[i][j], tol) {
+                    return Err(Error::NotTriangular.into());
+                }
+                matrix[i][j] = K::zero();
+            }
+        }
+        Ok(LowerTriangular { matrix })
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Returns the underlying [`Matrix`].
+    pub fn as_matrix(&self) -> &Matrix<K> {
+        &self.matrix
+    }
+
+    /// Computes the transpose, which is upper triangular.
+    pub fn transpose(&self) -> UpperTriangular<K> {
+        UpperTriangular {
+            matrix: self.matrix.as_view().transpose().to_matrix(),
+        }
+    }
+
+    /// Calculates the determinant as the product of the diagonal.
+    pub fn determinant(&self) -> K {
+        (0..self.size()).fold(K::one(), |acc, i| acc * self.matrix[i][i])
+    }
+
+    /// Multiplies by the given [`Vector`], skipping the zeros above the diagonal.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        let n = self.size();
+        assert_eq!(n, vec.len(), "dimensions of the operands must agree");
+        (0..n)
+            .map(|i| (0..=i).fold(K::zero(), |acc, j| acc + self.matrix[i][j] * vec[j]))
+            .collect()
+    }
+
+    /// Multiplies by another [`LowerTriangular`] matrix, which only involves the elements on and
+    /// below the diagonal and yields a lower triangular result.
+    pub fn mul_mat(&self, rhs: &LowerTriangular<K>) -> LowerTriangular<K> {
+        let n = self.size();
+        assert_eq!(n, rhs.size(), "inner dimensions of the matrices must agree");
+        let mut matrix = Matrix::from_vec(n, n, vec![K::zero(); n * n]).unwrap();
+        for i in 0..n {
+            for j in 0..=i {
+                matrix[i][j] = (j..=i).fold(K::zero(), |acc, k| {
+                    acc + self.matrix[i][k] * rhs.matrix[k][j]
+                });
+            }
+        }
+        LowerTriangular { matrix }
+    }
+
+    /// Solves `Lx = b` for `x` by forward substitution. Returns an error if a diagonal element is
+    /// not larger than the tolerance of [`TryFrom`], `ε` times the largest element in absolute
+    /// value.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        let n = check_rhs(self.size(), b)?;
+        check_diagonal(&self.matrix)?;
+        let mut x = b.clone();
+        for i in 0..n {
+            let s = (0..i).fold(x[i], |acc, j| acc - self.matrix[i][j] * x[j]);
+            x[i] = s / self.matrix[i][i];
+        }
+        Ok(x)
+    }
+}
+
+impl<K: Scalar> Symmetric<K> {
+    /// Packs the given square [`Matrix`], after checking that every pair of mirrored elements
+    /// differs by at most `tol`. The pairs are replaced by their average.
+    pub fn with_tol(a: &Matrix<K>, tol: K::Real) -> Result<Symmetric<K>> {
+        check_square(a)?;
+        let n = a.rows();
+        let mut data = Vec::with_capacity(n * (n + 1) / 2);
+        for i in 0..n {
+            for j in 0..=i {
+                if exceeds(a[i][j] - a[j][i], tol) {
+                    return Err(Error::NotSymmetric.into());
+                }
+                data.push((a[i][j] + a[j][i]) / (K::one() + K::one()));
+            }
+        }
+        Ok(Symmetric { data, n })
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the packed lower triangle, row by row.
+    pub fn packed(&self) -> &[K] {
+        &self.data
+    }
+
+    /// Multiplies by the given [`Vector`], reading every stored element once for both of the
+    /// positions it represents.
+    /// ```
+    /// # use matrix::{matrix::Matrix, structured::Symmetric, vector::Vector};
+    /// let u = Symmetric::try_from(&Matrix::from([[2., 1.], [1., 3.]])).unwrap();
+    /// assert_eq!(u.packed(), [2., 1., 3.]);
+    /// assert_eq!(u.mul_vec(&Vector::from([1., 2.])), Vector::from([4., 7.]));
+    /// ```
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        let mut res = vec![K::zero(); self.n];
+        let mut elements = self.data.iter();
+        for i in 0..self.n {
+            for j in 0..=i {
+                let a = *elements.next().unwrap();
+                res[i] += a * vec[j];
+                if i != j {
+                    res[j] += a * vec[i];
+                }
+            }
+        }
+        Vector::from(res)
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let n = self.n;
+        let data = (0..n * n).map(|k| self[(k / n, k % n)]).collect();
+        Matrix::from_vec(n, n, data).unwrap()
+    }
+}
+
+impl<K: RealScalar> Symmetric<K> {
+    /// Calculates the determinant from the `LDLᵀ` decomposition, which exploits the symmetry.
+    /// Returns an error if an element is NaN, since the decomposition then rejects the matrix.
+    pub fn determinant(&self) -> Result<K> {
+        Ok(Ldlt::new(&self.to_matrix())?.determinant())
+    }
+}
+
+impl<K: Scalar> Diagonal<K> {
+    /// Creates a [`Diagonal`] matrix from its diagonal.
+    pub fn new(diag: Vec<K>) -> Diagonal<K> {
+        Diagonal { diag }
+    }
+
+    /// Copies the diagonal of the given square [`Matrix`], after checking that no other element
+    /// exceeds `tol` in absolute value.
+    pub fn with_tol(a: &Matrix<K>, tol: K::Real) -> Result<Diagonal<K>> {
+        check_square(a)?;
+        let n = a.rows();
+        if (0..n).any(|i| (0..n).any(|j| i != j && exceeds(a[i][j], tol))) {
+            return Err(Error::NotDiagonal.into());
+        }
+        Ok(Diagonal {
+            diag: (0..n).map(|i| a[i][i]).collect(),
+        })
+    }
+
+    /// Returns the size `n`.
+    pub fn size(&self) -> usize {
+        self.diag.len()
+    }
+
+    /// Returns the diagonal.
+    pub fn diag(&self) -> &[K] {
+        &self.diag
+    }
+
+    /// Calculates the determinant as the product of the diagonal.
+    pub fn determinant(&self) -> K {
+        self.diag.iter().fold(K::one(), |acc, &d| acc * d)
+    }
+
+    /// Multiplies by the given [`Vector`], which scales its elements.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(
+            self.size(),
+            vec.len(),
+            "dimensions of the operands must agree"
+        );
+        self.diag
+            .iter()
+            .zip(vec.iter())
+            .map(|(d, x)| *d * *x)
+            .collect()
+    }
+
+    /// Multiplies by the given [`Matrix`], which scales its rows.
+    /// ```
+    /// # use matrix::{matrix::Matrix, structured::Diagonal};
+    /// let d = Diagonal::new(vec![2., -1.]);
+    /// let u = Matrix::from([[1., 2.], [3., 4.]]);
+    /// assert_eq!(d.mul_mat(&u), Matrix::from([[2., 4.], [-3., -4.]]));
+    /// ```
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+        assert_eq!(
+            self.size(),
+            mat.rows(),
+            "inner dimensions of the matrices must agree"
+        );
+        let mut res = mat.clone();
+        res.row_iter_mut()
+            .zip(&self.diag)
+            .for_each(|(row, &d)| row.iter_mut().for_each(|x| *x *= d));
+        res
+    }
+
+    /// Solves `Dx = b` for `x`. Returns an error if the diagonal contains an element that is NaN
+    /// or not above `ε` times the largest one in absolute value, as for the triangular types.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>> {
+        check_rhs(self.size(), b)?;
+        Ok(self.inverse()?.mul_vec(b))
+    }
+
+    /// Computes the inverse, which is diagonal as well. Returns an error if the diagonal contains
+    /// a numerical zero, see [`Diagonal::solve`].
+    pub fn inverse(&self) -> Result<Diagonal<K>> {
+        check_pivots(self.diag.iter().copied(), default_tolerance(&self.diag))?;
+        Ok(Diagonal {
+            diag: self.diag.iter().map(|&d| K::one() / d).collect(),
+        })
+    }
+
+    /// Copies the elements into a dense [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut res = Matrix::from_vec(n, n, vec![K::zero(); n * n]).unwrap();
+        self.diag
+            .iter()
+            .enumerate()
+            .for_each(|(i, &d)| res[i][i] = d);
+        res
+    }
+}
+
+impl<K> Index<(usize, usize)> for Symmetric<K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        &self.data[packed_index(self.n, i, j)]
+    }
+}
+
+/// Writing element `(i, j)` also writes element `(j, i)`.
+impl<K> IndexMut<(usize, usize)> for Symmetric<K> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        &mut self.data[packed_index(self.n, i, j)]
+    }
+}
+
+/// Checks the structure with a tolerance of `ε` times the largest element, see
+/// [`UpperTriangular::with_tol`].
+impl<K: Scalar> TryFrom<&Matrix<K>> for UpperTriangular<K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Matrix<K>) -> Result<Self> {
+        UpperTriangular::with_tol(value, default_tolerance(value.as_slice()))
+    }
+}
+
+/// Checks the structure with a tolerance of `ε` times the largest element, see
+/// [`LowerTriangular::with_tol`].
+impl<K: Scalar> TryFrom<&Matrix<K>> for LowerTriangular<K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Matrix<K>) -> Result<Self> {
+        LowerTriangular::with_tol(value, default_tolerance(value.as_slice()))
+    }
+}
+
+/// Checks the structure with a tolerance of `ε` times the largest element, see
+/// [`Symmetric::with_tol`].
+impl<K: Scalar> TryFrom<&Matrix<K>> for Symmetric<K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Matrix<K>) -> Result<Self> {
+        Symmetric::with_tol(value, default_tolerance(value.as_slice()))
+    }
+}
+
+/// Checks the structure with a tolerance of `ε` times the largest element, see
+/// [`Diagonal::with_tol`].
+impl<K: Scalar> TryFrom<&Matrix<K>> for Diagonal<K> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &Matrix<K>) -> Result<Self> {
+        Diagonal::with_tol(value, default_tolerance(value.as_slice()))
+    }
+}
+
+impl<K> From<UpperTriangular<K>> for Matrix<K> {
+    fn from(value: UpperTriangular<K>) -> Self {
+        value.matrix
+    }
+}
+
+impl<K> From<LowerTriangular<K>> for Matrix<K> {
+    fn from(value: LowerTriangular<K>) -> Self {
+        value.matrix
+    }
+}
+
+impl<K: Scalar> From<&Symmetric<K>> for Matrix<K> {
+    fn from(value: &Symmetric<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<K: Scalar> From<&Diagonal<K>> for Matrix<K> {
+    fn from(value: &Diagonal<K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+fn packed_index(n: usize, i: usize, j: usize) -> usize {
+    assert!(i < n && j < n, "index out of bounds");
+    let (i, j) = if i >= j { (i, j) } else { (j, i) };
+    i * (i + 1) / 2 + j
+}
+
+fn default_tolerance<K: Scalar>(elements: &[K]) -> K::Real {
+    elements
+        .iter()
+        .fold(K::Real::zero(), |max, x| max.max(x.modulus()))
+        * K::Real::epsilon()
+}
+
+/// Returns whether `x` exceeds `tol` in absolute value. NaN always does, so that it is never
+/// mistaken for a negligible element.
+fn exceeds<K: Scalar>(x: K, tol: K::Real) -> bool {
+    let modulus = x.modulus();
+    modulus > tol || modulus.is_nan()
+}
+
+/// Returns [`Error::Singular`] if a diagonal element of the triangular `a` is NaN or within
+/// [`default_tolerance`] of zero.
+fn check_diagonal<K: Scalar>(a: &Matrix<K>) -> Result<()> {
+    check_pivots(
+        (0..a.rows()).map(|i| a[i][i]),
+        default_tolerance(a.as_slice()),
+    )
+}
+
+fn check_pivots<K: Scalar>(mut pivots: impl Iterator<Item = K>, tol: K::Real) -> Result<()> {
+    if pivots.any(|d| {
+        let modulus = d.modulus();
+        modulus <= tol || modulus.is_nan()
+    }) {
+        return Err(Error::Singular.into());
+    }
+    Ok(())
+}
+
+fn check_square<K>(a: &Matrix<K>) -> Result<()> {
+    if a.rows() != a.columns() {
+        return Err(Error::NotSquare {
+            rows: a.rows(),
+            columns: a.columns(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Returns the size `n` if the right-hand side `b` has `n` elements.
+fn check_rhs<K>(n: usize, b: &Vector<K>) -> Result<usize> {
+    if b.len() != n {
+        return Err(Error::DimensionMismatch {
+            expected: n,
+            found: b.len(),
+        }
+        .into());
+    }
+    Ok(n)
+}
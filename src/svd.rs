@@ -0,0 +1,193 @@
+use crate::{matrix::Matrix, vector::Vector, view::MatrixView, Scalar};
+use num::{traits::float::FloatCore, NumCast, One, Zero};
+
+/// Maximum number of sweeps of the one-sided Jacobi method. Convergence is quadratic, so this is
+/// never reached in practice.
+const MAX_SWEEPS: usize = 100;
+
+/// Thin singular value decomposition `A = UΣVᴴ` of a [`Matrix`] of shape `m`x`n`, where `Vᴴ` is
+/// the conjugate transpose of `V`, i.e. `Vᵀ` for real matrices. With `k = min(m, n)`, `U` is
+/// `m`x`k` with orthonormal columns, `Σ` holds the `k` real singular values in descending order and
+/// `Vᴴ` is `k`x`n` with orthonormal rows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svd<K: Scalar> {
+    u: Matrix<K>,
+    singular_values: Vector<K::Real>,
+    vt: Matrix<K>,
+}
+
+impl<K: Scalar> Svd<K> {
+    /// Computes the decomposition of the given [`Matrix`] with the one-sided Jacobi method.
+    pub fn new<'a>(a: impl Into<MatrixView<'a, K>>) -> Svd<K>
+    where
+        K: 'a,
+    {
+        let a = a.into();
+        if a.rows() < a.columns() {
+            let svd = Svd::new(&a.conjugate_transpose());
+            return Svd {
+                u: svd.vt.conjugate_transpose(),
+                singular_values: svd.singular_values,
+                vt: svd.u.conjugate_transpose(),
+            };
+        }
+        let mut u = a.to_matrix();
+        let (m, n) = (u.m, u.n);
+        let mut v = Matrix::identity(n);
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (alpha, beta, gamma) = (0..m).fold(
+                        (K::Real::zero(), K::Real::zero(), K::zero()),
+                        |(a, b, g), i| {
+                            (
+                                a + u[i][p].modulus_sqr(),
+                                b + u[i][q].modulus_sqr(),
+                                g + u[i][p].conj() * u[i][q],
+                            )
+                        },
+                    );
+                    let modulus = gamma.modulus();
+                    if modulus == K::Real::zero()
+                        || modulus <= K::Real::epsilon() * (alpha * beta).sqrt()
+                    {
+                        continue;
+                    }
+                    rotated = true;
+                    // Rotates the columns after turning `gamma` real by the phase `e`.
+                    let e = gamma / K::from_real(modulus);
+                    let zeta = (beta - alpha) / (modulus + modulus);
+                    let one = K::Real::one();
+                    let t = zeta.signum() / (zeta.abs() + (one + zeta * zeta).sqrt());
+                    let c = one / (one + t * t).sqrt();
+                    let (c, s) = (K::from_real(c), K::from_real(c * t));
+                    for w in [&mut u, &mut v] {
+                        for i in 0..w.m {
+                            let (x, y) = (w[i][p], w[i][q]);
+                            w[i][p] = c * x - s * e.conj() * y;
+                            w[i][q] = s * e * x + c * y;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<K::Real> = (0..n)
+            .map(|j| {
+                (0..m)
+                    .fold(K::Real::zero(), |acc, i| acc + u[i][j].modulus_sqr())
+                    .sqrt()
+            })
+            .collect();
+        // NaN, which only comes from NaN input, sorts first instead of breaking the order.
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            (norms[b].partial_cmp(&norms[a]))
+                .unwrap_or_else(|| norms[b].is_nan().cmp(&norms[a].is_nan()))
+        });
+
+        let singular_values: Vector<K::Real> = order.iter().map(|&j| norms[j]).collect();
+        let tol = tolerance(m, &singular_values);
+        let mut res_u = Matrix::zeros(m, n);
+        let mut vt = Matrix::zeros(n, n);
+        for (k, &j) in order.iter().enumerate() {
+            if norms[j] > tol {
+                (0..m).for_each(|i| res_u[i][k] = u[i][j] / K::from_real(norms[j]));
+            }
+            (0..n).for_each(|i| vt[k][i] = v[i][j].conj());
+        }
+        complete_orthonormal_columns(&mut res_u, &singular_values, tol);
+
+        Svd {
+            u: res_u,
+            singular_values,
+            vt,
+        }
+    }
+
+    /// Returns the left singular vectors `U` as columns.
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    /// Returns the singular values in descending order.
+    pub fn singular_values(&self) -> &Vector<K::Real> {
+        &self.singular_values
+    }
+
+    /// Returns the right singular vectors `V` as rows of `Vᴴ`, i.e. conjugated for complex
+    /// matrices.
+    pub fn vt(&self) -> &Matrix<K> {
+        &self.vt
+    }
+
+    /// Default tolerance below which singular values are treated as zero:
+    /// `max(m, n) * ε * σ_max`.
+    pub fn default_tolerance(&self) -> K::Real {
+        tolerance(self.u.m.max(self.vt.n), &self.singular_values)
+    }
+
+    /// Computes the number of singular values larger than `tol`.
+    pub fn rank(&self, tol: K::Real) -> usize {
+        self.singular_values.iter().filter(|&&s| s > tol).count()
+    }
+
+    /// Computes the Moore–Penrose pseudo-inverse `VΣ⁺Uᴴ`, where singular values not larger than
+    /// `tol` are treated as zero.
+    pub fn pinv(&self, tol: K::Real) -> Matrix<K> {
+        let (m, n) = (self.u.m, self.vt.n);
+        let mut res = Matrix::zeros(n, m);
+        for (k, &s) in self.singular_values.iter().enumerate() {
+            if s <= tol {
+                continue;
+            }
+            for i in 0..n {
+                let factor = self.vt[k][i].conj() / K::from_real(s);
+                for j in 0..m {
+                    let tmp = factor * self.u[j][k].conj();
+                    res[i][j] += tmp;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Computes `size * ε * σ_max` for singular values in descending order.
+fn tolerance<R: FloatCore + NumCast>(size: usize, singular_values: &[R]) -> R {
+    let max = singular_values.first().copied().unwrap_or_else(R::zero);
+    <R as NumCast>::from(size).unwrap() * R::epsilon() * max
+}
+
+/// Replaces the columns of `u` belonging to singular values not larger than `tol` by unit vectors
+/// orthogonal to all other columns, so that `u` keeps orthonormal columns for rank-deficient
+/// matrices.
+fn complete_orthonormal_columns<K: Scalar>(
+    u: &mut Matrix<K>,
+    singular_values: &Vector<K::Real>,
+    tol: K::Real,
+) {
+    let m = u.m;
+    let mut candidates = 0..m;
+    for k in (0..u.n).filter(|&k| singular_values[k] <= tol) {
+        for e in candidates.by_ref() {
+            let mut w: Vec<K> = (0..m)
+                .map(|i| if i == e { K::one() } else { K::zero() })
+                .collect();
+            for j in (0..u.n).filter(|&j| j != k) {
+                let dot = (0..m).fold(K::zero(), |acc, i| acc + u[i][j].conj() * w[i]);
+                (0..m).for_each(|i| w[i] -= dot * u[i][j]);
+            }
+            let norm = w.iter().map(|x| x.modulus_sqr()).sum::<K::Real>().sqrt();
+            if norm > <K::Real as NumCast>::from(0.5).unwrap() {
+                (0..m).for_each(|i| u[i][k] = w[i] / K::from_real(norm));
+                break;
+            }
+        }
+    }
+}
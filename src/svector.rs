@@ -0,0 +1,176 @@
+use crate::{error::Error, vector::Vector, Scalar};
+use anyhow::Result;
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Stack-allocated vector of `N` elements of type `K`. Operations between vectors of different
+/// lengths are rejected at compile time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SVector<K, const N: usize> {
+    pub data: [K; N],
+}
+
+impl<K: Scalar, const N: usize> SVector<K, N> {
+    /// Creates an [`SVector`] filled with zeros.
+    pub fn zeros() -> Self {
+        SVector {
+            data: [K::zero(); N],
+        }
+    }
+
+    /// Returns the number of elements `N`.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Checks whether the [`SVector`] has no elements.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Iterates over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self.data.iter()
+    }
+
+    /// Dot product of [`SVector`], conjugating `self` for complex vectors like [`Vector::dot`].
+    /// ```
+    /// # use matrix::svector::SVector;
+    /// let u = SVector::from([-1., 6.]);
+    /// assert_eq!(u.dot(&SVector::from([3., 2.])), 9.);
+    /// ```
+    pub fn dot(&self, v: &SVector<K, N>) -> K {
+        self.iter()
+            .zip(v.iter())
+            .fold(K::zero(), |acc, (u, v)| acc + u.conj() * *v)
+    }
+
+    /// Also called the Euclidean norm.
+    pub fn norm(&self) -> K::Real {
+        self.iter().map(|x| x.modulus_sqr()).sum::<K::Real>().sqrt()
+    }
+}
+
+impl<K: Scalar> SVector<K, 3> {
+    /// Cross product of two 3-dimensional [`SVector`]s.
+    /// ```
+    /// # use matrix::svector::SVector;
+    /// let u = SVector::from([1., 2., 3.]);
+    /// assert_eq!(u.cross(&SVector::from([4., 5., 6.])), SVector::from([-3., 6., -3.]));
+    /// ```
+    pub fn cross(&self, v: &SVector<K, 3>) -> SVector<K, 3> {
+        let u = self;
+        SVector::from([
+            (u[1] * v[2]) - (u[2] * v[1]),
+            (u[2] * v[0]) - (u[0] * v[2]),
+            (u[0] * v[1]) - (u[1] * v[0]),
+        ])
+    }
+}
+
+impl<K, const N: usize> Index<usize> for SVector<K, N> {
+    type Output = K;
+
+    fn index(&self, i: usize) -> &K {
+        &self.data[i]
+    }
+}
+
+impl<K, const N: usize> IndexMut<usize> for SVector<K, N> {
+    fn index_mut(&mut self, i: usize) -> &mut K {
+        &mut self.data[i]
+    }
+}
+
+impl<K: Scalar, const N: usize> AddAssign for SVector<K, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(u, v)| *u += v);
+    }
+}
+
+impl<K: Scalar, const N: usize> Add for SVector<K, N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<K: Scalar, const N: usize> SubAssign for SVector<K, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(u, v)| *u -= v);
+    }
+}
+
+impl<K: Scalar, const N: usize> Sub for SVector<K, N> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<K: Scalar, const N: usize> MulAssign<K> for SVector<K, N> {
+    fn mul_assign(&mut self, rhs: K) {
+        self.data.iter_mut().for_each(|u| *u *= rhs);
+    }
+}
+
+impl<K: Scalar, const N: usize> Mul<K> for SVector<K, N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: K) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<K: Scalar, const N: usize> Neg for SVector<K, N> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.data.iter_mut().for_each(|u| *u = -*u);
+        self
+    }
+}
+
+impl<K, const N: usize> From<[K; N]> for SVector<K, N> {
+    fn from(data: [K; N]) -> Self {
+        SVector { data }
+    }
+}
+
+impl<K: Scalar, const N: usize> From<SVector<K, N>> for Vector<K> {
+    fn from(value: SVector<K, N>) -> Self {
+        Vector::from(value.data)
+    }
+}
+
+impl<K: Scalar, const N: usize> TryFrom<&Vector<K>> for SVector<K, N> {
+    type Error = anyhow::Error;
+
+    /// Converts a [`Vector`] of length `N`, returning an error for any other length.
+    fn try_from(value: &Vector<K>) -> Result<Self> {
+        let data = <[K; N]>::try_from(value.as_slice()).map_err(|_| Error::DimensionMismatch {
+            expected: N,
+            found: value.len(),
+        })?;
+        Ok(SVector { data })
+    }
+}
+
+impl<K: fmt::Debug, const N: usize> fmt::Display for SVector<K, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.data)
+    }
+}
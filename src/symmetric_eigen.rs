@@ -0,0 +1,107 @@
+use crate::{error::Error, matrix::Matrix, vector::Vector, view::MatrixView, Scalar};
+use anyhow::Result;
+use num::{traits::float::FloatCore, One, Zero};
+
+/// Default maximum number of sweeps of the cyclic Jacobi method.
+pub const DEFAULT_MAX_SWEEPS: usize = 50;
+
+/// Eigendecomposition `A = VΛVᴴ` of a Hermitian [`Matrix`], which for real matrices is the
+/// decomposition `A = VΛVᵀ` of a symmetric [`Matrix`]. The real eigenvalues in `Λ` are sorted in
+/// ascending order and the columns of the unitary `V` are the corresponding eigenvectors.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymmetricEigen<K: Scalar> {
+    eigenvalues: Vector<K::Real>,
+    eigenvectors: Matrix<K>,
+}
+
+impl<K: Scalar> SymmetricEigen<K> {
+    /// Diagonalizes the given Hermitian [`Matrix`] with the cyclic Jacobi method. The iteration
+    /// stops once the off-diagonal Frobenius norm drops below `tol` times the Frobenius norm of the
+    /// [`Matrix`], and returns an error if that does not happen within `max_sweeps` sweeps.
+    pub fn new<'a>(
+        a: impl Into<MatrixView<'a, K>>,
+        tol: K::Real,
+        max_sweeps: usize,
+    ) -> Result<SymmetricEigen<K>>
+    where
+        K: 'a,
+    {
+        let mut a = a.into().to_matrix();
+        if !a.is_hermitian() {
+            return Err(Error::NotSymmetric.into());
+        }
+        let n = a.n;
+        let mut v = Matrix::identity(n);
+        let norm = frobenius(&a, |_, _| true);
+
+        let mut sweeps = 0;
+        while frobenius(&a, |i, j| i != j) > tol * norm {
+            if sweeps == max_sweeps {
+                return Err(Error::NoConvergence {
+                    iterations: max_sweeps,
+                }
+                .into());
+            }
+            sweeps += 1;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let modulus = a[p][q].modulus();
+                    if modulus == K::Real::zero() {
+                        continue;
+                    }
+                    // Rotates after turning `a[p][q]` real by the phase `e`.
+                    let e = a[p][q] / K::from_real(modulus);
+                    let theta = (a[q][q].re() - a[p][p].re()) / (modulus + modulus);
+                    let one = K::Real::one();
+                    let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+                    let c = one / (t * t + one).sqrt();
+                    let (c, s) = (K::from_real(c), K::from_real(t * c));
+                    for w in [&mut a, &mut v] {
+                        for k in 0..n {
+                            let (x, y) = (w[k][p], w[k][q]);
+                            w[k][p] = c * x - s * e.conj() * y;
+                            w[k][q] = s * e * x + c * y;
+                        }
+                    }
+                    for k in 0..n {
+                        let (x, y) = (a[p][k], a[q][k]);
+                        a[p][k] = c * x - s * e * y;
+                        a[q][k] = s * e.conj() * x + c * y;
+                    }
+                    a[p][q] = K::zero();
+                    a[q][p] = K::zero();
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i][i].re().partial_cmp(&a[j][j].re()).unwrap());
+        let eigenvalues = order.iter().map(|&i| a[i][i].re()).collect();
+        let eigenvectors = (0..n)
+            .map(|i| order.iter().map(|&j| v[i][j]).collect())
+            .collect();
+        Ok(SymmetricEigen {
+            eigenvalues,
+            eigenvectors,
+        })
+    }
+
+    /// Returns the eigenvalues in ascending order.
+    pub fn eigenvalues(&self) -> &Vector<K::Real> {
+        &self.eigenvalues
+    }
+
+    /// Returns the orthonormal eigenvectors as columns, in the order of [`Self::eigenvalues`].
+    pub fn eigenvectors(&self) -> &Matrix<K> {
+        &self.eigenvectors
+    }
+}
+
+/// Frobenius norm of the entries `(i, j)` of `a` selected by `filter`.
+fn frobenius<K: Scalar>(a: &Matrix<K>, filter: impl Fn(usize, usize) -> bool) -> K::Real {
+    (0..a.m)
+        .flat_map(|i| (0..a.n).map(move |j| (i, j)))
+        .filter(|&(i, j)| filter(i, j))
+        .fold(K::Real::zero(), |acc, (i, j)| acc + a[i][j].modulus_sqr())
+        .sqrt()
+}
@@ -1,13 +1,10 @@
-use crate::{arithmetic, Scalar};
+use crate::{arithmetic, parallel, simd::Isa, Scalar};
 use derive_more::{Deref, DerefMut, Display, Index, IndexMut};
-use itertools::Itertools;
-use num::pow::Pow;
-use std::{
-    iter::Sum,
-    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
-};
-
-/// Vector struct that consists of a `Vec` of type `K` and it's shape. At this moment the implementation only works for `f32`. However, in the future implementation for complex numbers will be added as well, hence the generic type `K`.
+use num::{traits::float::FloatCore, Zero};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Vector struct that consists of a `Vec` of type `K`, which can be any [`Scalar`]: a real number
+/// or a complex number.
 #[derive(Clone, Debug, Default, Deref, DerefMut, Index, IndexMut, PartialEq, Display)]
 #[display(fmt = "{:?}", vector)]
 pub struct Vector<K> {
@@ -16,48 +13,59 @@ pub struct Vector<K> {
     #[index]
     #[index_mut]
     pub vector: Vec<K>,
-    size: usize,
 }
 
-impl<K: Scalar<K>> Vector<K>
-where
-    f32: Sum<K> + Sum<<K as Pow<f32>>::Output>,
-{
+impl<K: Scalar> Vector<K> {
     pub fn size(&self) -> usize {
-        self.size
+        self.vector.len()
     }
 
-    /// Dot product of [`Vector`].
+    /// Dot product of [`Vector`]. For complex vectors this is the Hermitian inner product
+    /// `Σ conj(uᵢ) vᵢ`, which is conjugate linear in `self`, so that `u.dot(&u)` is the squared
+    /// Euclidean norm.
     /// ```
+    /// # use matrix::vector::Vector;
+    /// # use num::Complex;
     /// let u = Vector::from([-1., 6.]);
     /// let v = Vector::from([3., 2.]);
-    /// assert_eq!(u.dot(v), 9.0);
+    /// assert_eq!(u.dot(&v), 9.0);
+    ///
+    /// let u = Vector::from([Complex::new(0., 1.), Complex::new(1., 0.)]);
+    /// let v = Vector::from([Complex::new(0., 1.), Complex::new(2., 0.)]);
+    /// assert_eq!(u.dot(&v), Complex::new(3., 0.));
     /// ```
-    pub fn dot(&self, v: Vector<K>) -> K {
-        self.iter().zip_eq(v.iter()).map(|(u, v)| *u * *v).sum()
+    pub fn dot(&self, v: &Vector<K>) -> K {
+        K::dotc(Isa::detect(), self, v)
     }
 
     /// Also called the taxicab norm or Manhattan norm.
-    pub fn norm_1(&mut self) -> f32 {
-        self.iter().map(|i| i.abs()).sum::<f32>()
+    pub fn norm_1(&self) -> K::Real {
+        self.iter().map(|i| i.modulus()).sum()
     }
 
     /// Also called the Euclidean norm.
-    pub fn norm(&mut self) -> f32 {
-        self.iter().map(|i| i.pow(2.)).sum::<f32>().sqrt()
+    /// ```
+    /// # use matrix::vector::Vector;
+    /// # use num::Complex;
+    /// let u = Vector::from([Complex::new(3., 4.), Complex::new(0., 0.)]);
+    /// assert_eq!(u.norm(), 5.);
+    /// ```
+    pub fn norm(&self) -> K::Real {
+        self.iter().map(|i| i.modulus_sqr()).sum::<K::Real>().sqrt()
     }
 
-    /// Also called the supremum norm.
-    pub fn norm_inf(&mut self) -> f32 {
+    /// Also called the supremum norm. It is NaN if an element is NaN, like the other norms.
+    pub fn norm_inf(&self) -> K::Real {
         self.iter()
-            .min_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap())
-            .unwrap()
-            .abs()
-            .into()
+            .map(|i| i.modulus())
+            .fold(
+                K::Real::zero(),
+                |max, x| if x > max || x.is_nan() { x } else { max },
+            )
     }
 }
 
-impl<K: Scalar<K>> Mul<K> for Vector<K> {
+impl<K: Scalar> Mul<K> for Vector<K> {
     type Output = Self;
 
     fn mul(self, f: K) -> Self::Output {
@@ -67,11 +75,17 @@ impl<K: Scalar<K>> Mul<K> for Vector<K> {
     }
 }
 
-impl<K: Scalar<K>> MulAssign<K> for Vector<K> {
+impl<K: Scalar> Mul<K> for &Vector<K> {
+    type Output = Vector<K>;
+
+    fn mul(self, f: K) -> Vector<K> {
+        self.clone() * f
+    }
+}
+
+impl<K: Scalar> MulAssign<K> for Vector<K> {
     fn mul_assign(&mut self, rhs: K) {
-        self.iter_mut().for_each(|u| {
-            *u *= rhs;
-        });
+        parallel::apply(self, |u| *u *= rhs);
     }
 }
 
@@ -79,17 +93,30 @@ arithmetic!(Vector, Add);
 arithmetic!(Vector, Sub);
 arithmetic!(Vector, Mul);
 
-impl<T: Into<Vec<K>>, K: Scalar<K>> From<T> for Vector<K> {
+impl<K: Scalar> Neg for Vector<K> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        parallel::apply(&mut self, |u| *u = -*u);
+        self
+    }
+}
+
+impl<K: Scalar> Neg for &Vector<K> {
+    type Output = Vector<K>;
+
+    fn neg(self) -> Vector<K> {
+        -self.clone()
+    }
+}
+
+impl<T: Into<Vec<K>>, K: Scalar> From<T> for Vector<K> {
     fn from(v: T) -> Self {
-        let vector = v.into();
-        Vector {
-            size: vector.len(),
-            vector,
-        }
+        Vector { vector: v.into() }
     }
 }
 
-impl<K: Scalar<K>> FromIterator<K> for Vector<K> {
+impl<K: Scalar> FromIterator<K> for Vector<K> {
     fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
         iter.into_iter().collect::<Vec<K>>().into()
     }
@@ -0,0 +1,554 @@
+use crate::{
+    gemm::gemm,
+    matrix::Matrix,
+    strassen::{strassen, Algorithm},
+    vector::Vector,
+    Scalar,
+};
+use num::traits::float::FloatCore;
+use std::ops::{
+    Add, AddAssign, Bound, Index, IndexMut, MulAssign, Range, RangeBounds, Sub, SubAssign,
+};
+
+/// Borrowed, possibly strided, rectangular block of a [`Matrix`]. Element `(i, j)` of the view
+/// lives at offset `i * row_stride + j * column_stride` of the borrowed buffer.
+#[derive(Debug)]
+pub struct MatrixView<'a, K> {
+    data: &'a [K],
+    m: usize,
+    n: usize,
+    strides: (usize, usize),
+}
+
+/// Mutable counterpart of [`MatrixView`], used to update a block of a [`Matrix`] in place.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, K> {
+    data: &'a mut [K],
+    m: usize,
+    n: usize,
+    strides: (usize, usize),
+}
+
+impl<K> Clone for MatrixView<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for MatrixView<'_, K> {}
+
+impl<'a, K> MatrixView<'a, K> {
+    pub(crate) fn new(data: &'a [K], m: usize, n: usize, strides: (usize, usize)) -> Self {
+        let len = extent(m, n, strides);
+        MatrixView {
+            data: &data[..len],
+            m,
+            n,
+            strides,
+        }
+    }
+
+    /// Returns the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns of the view.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the distance in the underlying buffer between consecutive rows and between
+    /// consecutive columns.
+    pub fn strides(&self) -> (usize, usize) {
+        self.strides
+    }
+
+    /// Returns a reference to element `(i, j)`, or `None` if it is out of bounds.
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a K> {
+        (i < self.m && j < self.n).then(|| &self.data[i * self.strides.0 + j * self.strides.1])
+    }
+
+    /// Returns row `i` as a slice if its elements are contiguous in the underlying buffer.
+    pub(crate) fn row_slice(&self, i: usize) -> Option<&'a [K]> {
+        let start = i * self.strides.0;
+        (self.strides.1 == 1 || self.n <= 1).then(|| &self.data[start..start + self.n])
+    }
+
+    /// Iterates over the elements of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a K> {
+        let view = *self;
+        (0..view.m).flat_map(move |i| (0..view.n).map(move |j| view.get(i, j).unwrap()))
+    }
+
+    /// Returns the block of the view spanned by the given row and column ranges.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    /// let block = u.view(1.., ..2);
+    /// assert_eq!(block.to_matrix(), Matrix::from([[4., 5.], [7., 8.]]));
+    /// assert_eq!(block.view(1..2, 1..2)[(0, 0)], 8.);
+    /// ```
+    pub fn view(&self, rows: impl RangeBounds<usize>, columns: impl RangeBounds<usize>) -> Self {
+        let (rows, columns) = (range(rows, self.m), range(columns, self.n));
+        let offset = offset(&rows, &columns, self.strides);
+        MatrixView::new(
+            &self.data[offset..],
+            rows.len(),
+            columns.len(),
+            self.strides,
+        )
+    }
+
+    /// Returns row `i` as a `1`x`n` view.
+    pub fn row(&self, i: usize) -> Self {
+        self.view(i..=i, ..)
+    }
+
+    /// Returns column `j` as an `m`x`1` view.
+    pub fn col(&self, j: usize) -> Self {
+        self.view(.., j..=j)
+    }
+
+    /// Returns the main diagonal as a `min(m, n)`x`1` view.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2., 3.], [4., 5., 6.]]);
+    /// assert_eq!(u.diag().to_matrix(), Matrix::from([[1.], [5.]]));
+    /// ```
+    pub fn diag(&self) -> Self {
+        MatrixView::new(
+            self.data,
+            self.m.min(self.n),
+            1,
+            (self.strides.0 + self.strides.1, 1),
+        )
+    }
+
+    /// Returns the view of every `row_step`-th row and every `column_step`-th column, starting
+    /// with the first.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    /// assert_eq!(u.view(.., ..).strided(2, 2).to_matrix(), Matrix::from([[1., 3.], [7., 9.]]));
+    /// ```
+    pub fn strided(&self, row_step: usize, column_step: usize) -> Self {
+        assert!(row_step > 0 && column_step > 0, "steps must be positive");
+        MatrixView::new(
+            self.data,
+            self.m.div_ceil(row_step),
+            self.n.div_ceil(column_step),
+            (self.strides.0 * row_step, self.strides.1 * column_step),
+        )
+    }
+
+    /// Returns the transpose of the view without copying, by swapping its strides.
+    pub fn transpose(&self) -> Self {
+        MatrixView {
+            data: self.data,
+            m: self.n,
+            n: self.m,
+            strides: (self.strides.1, self.strides.0),
+        }
+    }
+}
+
+impl<K: Scalar> MatrixView<'_, K> {
+    /// Checks whether the view is square and equal to its transpose, up to rounding errors.
+    pub fn is_symmetric(&self) -> bool {
+        self.is_close_to_transpose(|x| x)
+    }
+
+    /// Checks whether the view is square and equal to its conjugate transpose, up to rounding
+    /// errors. For real matrices this is the same as [`Self::is_symmetric`].
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// # use num::Complex;
+    /// let u = Matrix::from([
+    ///     [Complex::new(2., 0.), Complex::new(1., -1.)],
+    ///     [Complex::new(1., 1.), Complex::new(3., 0.)],
+    /// ]);
+    /// assert!(u.as_view().is_hermitian());
+    /// assert!(!u.as_view().is_symmetric());
+    /// ```
+    pub fn is_hermitian(&self) -> bool {
+        self.is_close_to_transpose(K::conj)
+    }
+
+    /// Checks whether the view is square and every element `(i, j)` is close to `f` applied to
+    /// element `(j, i)`.
+    fn is_close_to_transpose(&self, f: impl Fn(K) -> K) -> bool {
+        self.m == self.n
+            && (0..self.m).all(|i| {
+                (0..=i).all(|j| {
+                    let (a, b) = (self[(i, j)], f(self[(j, i)]));
+                    (a - b).modulus() <= K::Real::epsilon() * (a.modulus() + b.modulus())
+                })
+            })
+    }
+
+    /// Copies the conjugate transpose `Aᴴ` of the view into a new [`Matrix`]. For real matrices
+    /// this is the transpose.
+    pub fn conjugate_transpose(&self) -> Matrix<K> {
+        let mut res = self.transpose().to_matrix();
+        res.as_mut_slice().iter_mut().for_each(|x| *x = x.conj());
+        res
+    }
+
+    /// Copies the view into a new [`Matrix`].
+    pub fn to_matrix(&self) -> Matrix<K> {
+        Matrix::from_vec(self.m, self.n, self.iter().copied().collect()).unwrap()
+    }
+
+    /// Copies the elements of the view in row-major order into a new [`Vector`], which is most
+    /// useful for views of a single row or column.
+    pub fn to_vector(&self) -> Vector<K> {
+        self.iter().copied().collect()
+    }
+
+    /// Multiplies the view by the given [`Vector`].
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        assert_eq!(self.n, vec.len(), "dimensions of the operands must agree");
+        (0..self.m)
+            .map(|i| (0..self.n).fold(K::zero(), |acc, j| acc + self[(i, j)] * vec[j]))
+            .collect()
+    }
+
+    /// Multiplies the view by the given [`Matrix`] or view.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2., 0.], [3., 4., 0.]]);
+    /// let v = Matrix::from([[1., 0.], [0., 1.]]);
+    /// assert_eq!(u.view(.., ..2).mul_mat(&v), Matrix::from([[1., 2.], [3., 4.]]));
+    /// ```
+    pub fn mul_mat<'b>(&self, mat: impl Into<MatrixView<'b, K>>) -> Matrix<K>
+    where
+        K: 'b,
+    {
+        let mat = mat.into();
+        let mut res = Matrix::zeros(self.m, mat.n);
+        self.mul_mat_into(mat, &mut res);
+        res
+    }
+
+    /// Multiplies the view by the given [`Matrix`] or view with the chosen [`Algorithm`].
+    /// ```
+    /// # use matrix::{matrix::Matrix, strassen::Algorithm};
+    /// let u = Matrix::from([[1., 2.], [3., 4.]]);
+    /// let strassen = Algorithm::Strassen { cutoff: 1 };
+    /// assert_eq!(u.as_view().mul_mat_with(&u, strassen), u.as_view().mul_mat(&u));
+    /// ```
+    pub fn mul_mat_with<'b>(
+        &self,
+        mat: impl Into<MatrixView<'b, K>>,
+        algorithm: Algorithm,
+    ) -> Matrix<K>
+    where
+        K: 'b,
+    {
+        match algorithm {
+            Algorithm::Classical => self.mul_mat(mat),
+            Algorithm::Strassen { cutoff } => strassen(*self, mat.into(), cutoff),
+        }
+    }
+
+    /// Multiplies the view by the given [`Matrix`] or view and writes the product into `out`, a
+    /// preallocated matrix or mutable view of the right shape, without allocating a result.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let u = Matrix::from([[1., 2.], [3., 4.]]);
+    /// let mut out = Matrix::zeros(2, 2);
+    /// u.as_view().mul_mat_into(&u, &mut out);
+    /// assert_eq!(out, Matrix::from([[7., 10.], [15., 22.]]));
+    /// ```
+    pub fn mul_mat_into<'b, 'c>(
+        &self,
+        mat: impl Into<MatrixView<'b, K>>,
+        out: impl Into<MatrixViewMut<'c, K>>,
+    ) where
+        K: 'b + 'c,
+    {
+        gemm(K::one(), *self, mat, K::zero(), out);
+    }
+}
+
+impl<'a, K> MatrixViewMut<'a, K> {
+    pub(crate) fn new(data: &'a mut [K], m: usize, n: usize, strides: (usize, usize)) -> Self {
+        let len = extent(m, n, strides);
+        MatrixViewMut {
+            data: &mut data[..len],
+            m,
+            n,
+            strides,
+        }
+    }
+
+    /// Returns the number of rows of the view.
+    pub fn rows(&self) -> usize {
+        self.m
+    }
+
+    /// Returns the number of columns of the view.
+    pub fn columns(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the distance in the underlying buffer between consecutive rows and between
+    /// consecutive columns.
+    pub fn strides(&self) -> (usize, usize) {
+        self.strides
+    }
+
+    /// Returns the borrowed buffer, which holds the elements at their strided offsets.
+    pub(crate) fn data_mut(&mut self) -> &mut [K] {
+        self.data
+    }
+
+    /// Reborrows the view immutably.
+    pub fn as_view(&self) -> MatrixView<'_, K> {
+        MatrixView::new(self.data, self.m, self.n, self.strides)
+    }
+
+    /// Returns a mutable reference to element `(i, j)`, or `None` if it is out of bounds.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut K> {
+        (i < self.m && j < self.n).then(|| &mut self.data[i * self.strides.0 + j * self.strides.1])
+    }
+
+    /// Returns the mutable block of the view spanned by the given row and column ranges.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, K> {
+        self.reborrow().into_view(rows, columns)
+    }
+
+    /// Turns the view into the mutable block spanned by the given row and column ranges, keeping
+    /// the original lifetime.
+    pub fn into_view(
+        self,
+        rows: impl RangeBounds<usize>,
+        columns: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'a, K> {
+        let (rows, columns) = (range(rows, self.m), range(columns, self.n));
+        let offset = offset(&rows, &columns, self.strides);
+        MatrixViewMut::new(
+            &mut self.data[offset..],
+            rows.len(),
+            columns.len(),
+            self.strides,
+        )
+    }
+
+    /// Returns row `i` as a mutable `1`x`n` view.
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, K> {
+        self.view_mut(i..=i, ..)
+    }
+
+    /// Returns column `j` as a mutable `m`x`1` view.
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, K> {
+        self.view_mut(.., j..=j)
+    }
+
+    /// Returns the main diagonal as a mutable `min(m, n)`x`1` view.
+    pub fn diag_mut(&mut self) -> MatrixViewMut<'_, K> {
+        self.reborrow().into_diag()
+    }
+
+    /// Turns the view into its main diagonal, keeping the original lifetime.
+    pub fn into_diag(self) -> MatrixViewMut<'a, K> {
+        let stride = self.strides.0 + self.strides.1;
+        MatrixViewMut::new(self.data, self.m.min(self.n), 1, (stride, 1))
+    }
+
+    fn reborrow(&mut self) -> MatrixViewMut<'_, K> {
+        MatrixViewMut {
+            data: self.data,
+            m: self.m,
+            n: self.n,
+            strides: self.strides,
+        }
+    }
+
+    /// Applies `f` to every element of the view.
+    pub fn apply(&mut self, mut f: impl FnMut(&mut K)) {
+        for i in 0..self.m {
+            for j in 0..self.n {
+                f(&mut self[(i, j)]);
+            }
+        }
+    }
+}
+
+impl<K: Scalar> MatrixViewMut<'_, K> {
+    /// Sets every element of the view to `value`.
+    pub fn fill(&mut self, value: K) {
+        self.apply(|x| *x = value);
+    }
+
+    /// Overwrites the view with the elements of a [`Matrix`] or view of the same shape.
+    /// ```
+    /// # use matrix::matrix::Matrix;
+    /// let mut u = Matrix::zeros(3, 3);
+    /// u.view_mut(1.., 1..).copy_from(&Matrix::identity(2));
+    /// assert_eq!(u, Matrix::from([[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]]));
+    /// ```
+    pub fn copy_from<'b>(&mut self, src: impl Into<MatrixView<'b, K>>)
+    where
+        K: 'b,
+    {
+        let src = src.into();
+        assert_eq!((self.m, self.n), (src.m, src.n), "shapes must agree");
+        for i in 0..self.m {
+            for j in 0..self.n {
+                self[(i, j)] = src[(i, j)];
+            }
+        }
+    }
+}
+
+impl<'a, K> Index<(usize, usize)> for MatrixView<'a, K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        self.get(i, j).expect("index out of bounds")
+    }
+}
+
+impl<K> Index<(usize, usize)> for MatrixViewMut<'_, K> {
+    type Output = K;
+
+    fn index(&self, (i, j): (usize, usize)) -> &K {
+        assert!(i < self.m && j < self.n, "index out of bounds");
+        &self.data[i * self.strides.0 + j * self.strides.1]
+    }
+}
+
+impl<K> IndexMut<(usize, usize)> for MatrixViewMut<'_, K> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut K {
+        self.get_mut(i, j).expect("index out of bounds")
+    }
+}
+
+impl<K: PartialEq> PartialEq for MatrixView<'_, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.m == other.m && self.n == other.n && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, K> From<&'a Matrix<K>> for MatrixView<'a, K> {
+    fn from(value: &'a Matrix<K>) -> Self {
+        MatrixView::new(
+            value.as_slice(),
+            value.rows(),
+            value.columns(),
+            value.strides(),
+        )
+    }
+}
+
+impl<'a, K> From<&'a MatrixViewMut<'_, K>> for MatrixView<'a, K> {
+    fn from(value: &'a MatrixViewMut<'_, K>) -> Self {
+        value.as_view()
+    }
+}
+
+impl<'a, K> From<&'a mut Matrix<K>> for MatrixViewMut<'a, K> {
+    fn from(value: &'a mut Matrix<K>) -> Self {
+        let (m, n, strides) = (value.rows(), value.columns(), value.strides());
+        MatrixViewMut::new(value.as_mut_slice(), m, n, strides)
+    }
+}
+
+impl<K: Scalar> From<MatrixView<'_, K>> for Matrix<K> {
+    fn from(value: MatrixView<'_, K>) -> Self {
+        value.to_matrix()
+    }
+}
+
+impl<'b, K: Scalar + 'b, T: Into<MatrixView<'b, K>>> AddAssign<T> for MatrixViewMut<'_, K> {
+    fn add_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n), "shapes must agree");
+        for i in 0..self.m {
+            for j in 0..self.n {
+                self[(i, j)] += rhs[(i, j)];
+            }
+        }
+    }
+}
+
+impl<'b, K: Scalar + 'b, T: Into<MatrixView<'b, K>>> SubAssign<T> for MatrixViewMut<'_, K> {
+    fn sub_assign(&mut self, rhs: T) {
+        let rhs = rhs.into();
+        assert_eq!((self.m, self.n), (rhs.m, rhs.n), "shapes must agree");
+        for i in 0..self.m {
+            for j in 0..self.n {
+                self[(i, j)] -= rhs[(i, j)];
+            }
+        }
+    }
+}
+
+impl<K: Scalar> MulAssign<K> for MatrixViewMut<'_, K> {
+    fn mul_assign(&mut self, rhs: K) {
+        self.apply(|x| *x *= rhs);
+    }
+}
+
+impl<'b, K: Scalar + 'b, T: Into<MatrixView<'b, K>>> Add<T> for MatrixView<'_, K> {
+    type Output = Matrix<K>;
+
+    fn add(self, rhs: T) -> Matrix<K> {
+        let mut res = self.to_matrix();
+        MatrixViewMut::from(&mut res).add_assign(rhs);
+        res
+    }
+}
+
+impl<'b, K: Scalar + 'b, T: Into<MatrixView<'b, K>>> Sub<T> for MatrixView<'_, K> {
+    type Output = Matrix<K>;
+
+    fn sub(self, rhs: T) -> Matrix<K> {
+        let mut res = self.to_matrix();
+        MatrixViewMut::from(&mut res).sub_assign(rhs);
+        res
+    }
+}
+
+/// Number of elements of the underlying buffer spanned by a view.
+fn extent(m: usize, n: usize, (row_stride, column_stride): (usize, usize)) -> usize {
+    if m == 0 || n == 0 {
+        0
+    } else {
+        (m - 1) * row_stride + (n - 1) * column_stride + 1
+    }
+}
+
+/// Offset in the underlying buffer of the first element of a block, or zero for an empty block.
+fn offset(rows: &Range<usize>, columns: &Range<usize>, strides: (usize, usize)) -> usize {
+    if rows.is_empty() || columns.is_empty() {
+        0
+    } else {
+        rows.start * strides.0 + columns.start * strides.1
+    }
+}
+
+/// Resolves `range` against a dimension of length `len`, panicking if it is out of bounds.
+fn range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "range {start}..{end} out of bounds for length {len}"
+    );
+    start..end
+}